@@ -0,0 +1,120 @@
+#![allow(dead_code)]
+
+use std::collections::VecDeque;
+
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+/// Creates a tree containing only a root value:
+///
+/// ```md
+/// 0
+/// ```
+pub fn single_root_tree() -> Tree<DefaultKey, usize> {
+    complete_tree(1, 0)
+}
+
+/// Creates a tree with 2 levels, in which the root value has 3 children
+/// values:
+///
+/// ```md
+/// 0
+/// |-- 1
+/// |-- 2
+/// |-- 3
+/// ```
+pub fn depth_2_tree() -> Tree<DefaultKey, usize> {
+    complete_tree(2, 3)
+}
+
+/// Creates a tree with 4 levels, in which each value has exactly one child
+/// value (i.e., a linked-list):
+///
+/// ```md
+/// 0
+/// |-- 1
+///     |-- 2
+///         |-- 3
+/// ```
+pub fn linear_depth_4_tree() -> Tree<DefaultKey, usize> {
+    complete_tree(4, 1)
+}
+
+/// Creates a complete 3-ary tree with 4 levels.
+///
+/// Contains `(3^4 - 1) / (3 - 1) = 40` values, 27 of which are leaves:
+///
+/// ```md
+/// 0
+/// |-- 1
+///     |-- 4
+///         |-- 13
+///         |-- 14
+///         |-- 15
+///     |-- 5
+///         |-- ..
+///     |-- 6
+///         |-- ..
+/// |-- 2
+///     |-- ..
+/// |-- 3
+///     |-- ..
+/// ```
+pub fn medium() -> Tree<DefaultKey, usize> {
+    complete_tree(4, 3)
+}
+
+/// Creates a complete 4-ary tree with 4 levels.
+///
+/// Contains `(4^4 - 1) / (4 - 1) = 85` values, 64 of which are leaves:
+///
+/// ```md
+/// 0
+/// |-- 1
+///     |-- 5
+///         |-- 21
+///         |-- 22
+///         |-- 23
+///         |-- 24
+///     |-- ..
+/// |-- ..
+/// ```
+pub fn large() -> Tree<DefaultKey, usize> {
+    complete_tree(4, 4)
+}
+
+/// Creates a complete `branching`-ary tree with `depth` levels.
+///
+/// The values are numbered level by level (i.e., in breadth-first order),
+/// starting with `0` at the root. A complete tree contains
+/// `(branching^depth - 1) / (branching - 1)` values (or `depth` values if
+/// `branching` is `1`).
+pub fn complete_tree(depth: usize, branching: usize) -> Tree<DefaultKey, usize> {
+    let length = match branching {
+        0 => depth.min(1),
+        1 => depth,
+        _ => (branching.pow(depth as u32) - 1) / (branching - 1),
+    };
+
+    let mut tree = Tree::with_capacity(length);
+
+    if depth == 0 {
+        return tree;
+    };
+
+    let mut value = 0;
+    let root_key = tree.insert_root_with_capacity(value, branching);
+    let mut to_visit_keys = VecDeque::from([(root_key, 1)]);
+
+    while let Some((key, level)) = to_visit_keys.pop_front() {
+        if level < depth {
+            for _ in 0..branching {
+                value += 1;
+                let child_key = tree.insert_with_capacity(value, key, branching).unwrap();
+                to_visit_keys.push_back((child_key, level + 1));
+            }
+        };
+    }
+
+    tree
+}
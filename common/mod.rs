@@ -31,13 +31,18 @@ macro_rules! node {
     }};
 }
 
-use std::collections::BTreeMap;
+pub mod fixtures;
 
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+};
+
+use slotmap::DefaultKey;
 use tinytree::{
     Relationship,
     Tree,
 };
-use slotmap::DefaultKey;
 
 #[derive(Clone)]
 pub struct DeclarativeTree<K, V>
@@ -60,8 +65,8 @@ where
         declarative_node.into()
     }
 
-    pub fn into_declarative_node(&self) -> Option<DeclarativeNode<K, V>> {
-        self.into()
+    pub fn into_declarative_node(self) -> Option<DeclarativeNode<K, V>> {
+        (&self).into()
     }
 
     // Insertion/removal methods:
@@ -84,6 +89,30 @@ where
             .is_some()
     }
 
+    pub fn insert_before(&mut self, id: K, value: V, sibling_id: K) -> bool {
+        let sibling_key = get_or_default(&self.key_map, sibling_id);
+
+        self.tree
+            .insert_before(value, sibling_key)
+            .map(|key| {
+                let previous_value = self.key_map.insert(id, key);
+                assert!(previous_value.is_none());
+            })
+            .is_some()
+    }
+
+    pub fn insert_after(&mut self, id: K, value: V, sibling_id: K) -> bool {
+        let sibling_key = get_or_default(&self.key_map, sibling_id);
+
+        self.tree
+            .insert_after(value, sibling_key)
+            .map(|key| {
+                let previous_value = self.key_map.insert(id, key);
+                assert!(previous_value.is_none());
+            })
+            .is_some()
+    }
+
     pub fn reorder_children<F>(&mut self, id: K, get_reordered_ids: F) -> bool
     where
         F: FnOnce(&Vec<K>) -> Vec<K>,
@@ -97,7 +126,7 @@ where
         let did_reorder = self.tree.reorder_children(key, |current_child_keys| {
             let current_child_ids = current_child_keys
                 .iter()
-                .map(|current_child_key| *inverse_key_map.get(&current_child_key).unwrap())
+                .map(|current_child_key| *inverse_key_map.get(current_child_key).unwrap())
                 .collect();
 
             let mut reordered_children = get_reordered_ids(&current_child_ids);
@@ -130,6 +159,43 @@ where
         did_reorder
     }
 
+    pub fn sort_children_by<F>(&mut self, id: K, compare: F) -> bool
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.sort_children_by(key, compare)
+    }
+
+    pub fn sort_children_by_key<B, F>(&mut self, id: K, f: F) -> bool
+    where
+        F: FnMut(&V) -> B,
+        B: Ord,
+    {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.sort_children_by_key(key, f)
+    }
+
+    pub fn move_child_to_index(&mut self, id: K, new_index: usize) -> bool {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.move_child_to_index(key, new_index)
+    }
+
+    pub fn move_child_to_front(&mut self, id: K) -> bool {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.move_child_to_front(key)
+    }
+
+    pub fn move_child_to_back(&mut self, id: K) -> bool {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.move_child_to_back(key)
+    }
+
     pub fn remove(&mut self, id: K) -> Option<V> {
         let key = get_or_default(&self.key_map, id);
         let value = self.tree.remove(key, None);
@@ -141,6 +207,60 @@ where
         value
     }
 
+    pub fn take_leaf(&mut self, id: K) -> Option<V> {
+        let key = get_or_default(&self.key_map, id);
+        let value = self.tree.take_leaf(key);
+
+        if value.is_some() {
+            self.key_map.remove(&id).unwrap();
+        };
+
+        value
+    }
+
+    pub fn remove_children(&mut self, id: K) -> Option<usize> {
+        let key = get_or_default(&self.key_map, id);
+        let removed_count = self.tree.remove_children(key, None);
+
+        self.key_map.retain(|_, key| self.tree.contains(*key));
+
+        removed_count
+    }
+
+    pub fn remove_and_reparent(&mut self, id: K) -> Option<V> {
+        let key = get_or_default(&self.key_map, id);
+        let value = self.tree.remove_and_reparent(key);
+
+        if value.is_some() {
+            self.key_map.remove(&id).unwrap();
+        };
+
+        value
+    }
+
+    pub fn delete(&mut self, id: K) -> bool {
+        let key = get_or_default(&self.key_map, id);
+        let did_delete = self.tree.delete(key);
+
+        if did_delete {
+            self.key_map.remove(&id).unwrap();
+        };
+
+        did_delete
+    }
+
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K, &V) -> bool,
+    {
+        let inverse_key_map = invert(&self.key_map);
+
+        self.tree
+            .retain(|key, value| f(*inverse_key_map.get(&key).unwrap(), value));
+
+        self.key_map.retain(|_, key| self.tree.contains(*key));
+    }
+
     pub fn rebase(&mut self, id: K, new_parent_id: K) -> bool {
         let key = get_or_default(&self.key_map, id);
         let new_parent_key = get_or_default(&self.key_map, new_parent_id);
@@ -148,6 +268,26 @@ where
         self.tree.rebase(key, new_parent_key)
     }
 
+    pub fn rebase_at(&mut self, id: K, new_parent_id: K, index: usize) -> bool {
+        let key = get_or_default(&self.key_map, id);
+        let new_parent_key = get_or_default(&self.key_map, new_parent_id);
+
+        self.tree.rebase_at(key, new_parent_key, index)
+    }
+
+    pub fn reroot(&mut self, id: K) -> bool {
+        let key = get_or_default(&self.key_map, id);
+
+        self.tree.reroot(key)
+    }
+
+    pub fn swap_node_positions(&mut self, id_1: K, id_2: K) -> bool {
+        let key_1 = get_or_default(&self.key_map, id_1);
+        let key_2 = get_or_default(&self.key_map, id_2);
+
+        self.tree.swap_node_positions(key_1, key_2)
+    }
+
     // Getter/setter methods:
 
     pub fn get_relationship(&self, id_1: K, id_2: K) -> Option<Relationship<K>> {
@@ -2,9 +2,11 @@
 //! insert a root value and 3 children values into it. In this example, the
 //! children values will be *direct* children of the root value.
 
-use tinytree::Tree;
-use indexmap::IndexSet;
 use slotmap::DefaultKey;
+use tinytree::{
+    KeySet,
+    Tree,
+};
 
 fn main() {
     let mut tree = Tree::<DefaultKey, usize>::default();
@@ -24,7 +26,7 @@ fn main() {
         root_node.child_keys.clone(),
         vec![child_key_1, child_key_2, child_key_3]
             .into_iter()
-            .collect::<IndexSet<_>>(),
+            .collect::<KeySet<_>>(),
     );
 
     let child_node_1 = tree.get(child_key_1).unwrap();
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
 //! A small, simple, and correct tree implementation.
@@ -34,11 +35,49 @@
 //! # }
 //! ```
 
-use std::mem::replace;
+extern crate alloc;
+
+use alloc::{
+    collections::VecDeque,
+    format,
+    rc::Rc,
+    string::{
+        String,
+        ToString,
+    },
+    vec,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    convert::Infallible,
+    error::Error,
+    fmt,
+    hash::{
+        BuildHasher,
+        BuildHasherDefault,
+        Hash,
+        Hasher,
+    },
+    iter::{
+        from_fn,
+        FusedIterator,
+    },
+    mem::{
+        replace,
+        swap,
+        take,
+    },
+    ops::{
+        Index,
+        IndexMut,
+    },
+};
 
 use indexmap::IndexSet;
 use slotmap::{
     Key,
+    SecondaryMap,
     SlotMap,
 };
 
@@ -78,6 +117,392 @@ where
         }
     }
 
+    /// Create a new [`Tree`] instance with enough capacity pre-allocated to
+    /// hold a complete `branching`-ary tree with `levels` levels.
+    ///
+    /// Such a tree contains `(branching^levels - 1) / (branching - 1)` values
+    /// (e.g., a complete 3-ary tree with 4 levels contains `1 + 3 + 9 + 27 =
+    /// 40` values).
+    pub fn with_capacity_for(levels: usize, branching: usize) -> Self {
+        let mut capacity = 0_usize;
+        let mut level_capacity = 1_usize;
+
+        for _ in 0..levels {
+            capacity = capacity.saturating_add(level_capacity);
+            level_capacity = level_capacity.saturating_mul(branching);
+        }
+
+        Self::with_capacity(capacity)
+    }
+
+    /// Create a new [`Tree`] instance by adopting a [`SlotMap`] of
+    /// `(parent_key, value)` pairs.
+    ///
+    /// The given `parent_pointers` must contain exactly one entry with a
+    /// `parent_key` of [`None`] (i.e., the root), every `parent_key` must refer
+    /// to an entry inside of `parent_pointers`, and following the
+    /// `parent_key`s upwards from any entry must eventually reach the root
+    /// (i.e., there must be no cycles). If any of these conditions are not
+    /// met, then the corresponding [`BuildError`] is returned.
+    ///
+    /// The children of each value are ordered by the iteration order of
+    /// `parent_pointers`.
+    ///
+    /// Alongside the new [`Tree`] instance, a [`SecondaryMap`] is returned
+    /// which maps each key of `parent_pointers` to its corresponding key inside
+    /// of the new [`Tree`] instance.
+    ///
+    /// # Note:
+    /// A [`SlotMap`] mints its own keys upon insertion. Therefore, the keys of
+    /// the returned [`Tree`] instance will *not* line up with the keys of
+    /// `parent_pointers` if any value was ever removed from
+    /// `parent_pointers`. Always translate the old keys through the returned
+    /// [`SecondaryMap`].
+    pub fn from_parent_pointers(
+        parent_pointers: ParentPointers<K, V>,
+    ) -> Result<(Self, SecondaryMap<K, K>), BuildError<K>> {
+        /// Validates that `parent_pointers` describes a proper tree, returning
+        /// the key of its root.
+        fn validate<K, V>(parent_pointers: &SlotMap<K, (Option<K>, V)>) -> Result<K, BuildError<K>>
+        where
+            K: Key,
+        {
+            let mut root_key = None;
+
+            for (key, &(parent_key, _)) in parent_pointers {
+                match parent_key {
+                    Some(parent_key) if !parent_pointers.contains_key(parent_key) => {
+                        return Err(BuildError::MissingParent { key, parent_key })
+                    }
+                    Some(..) => (),
+                    None if root_key.is_some() => return Err(BuildError::MultipleRoots),
+                    None => root_key = Some(key),
+                }
+            }
+
+            let root_key = root_key.ok_or(BuildError::NoRoot)?;
+
+            let length = parent_pointers.len();
+            let mut verified_keys = index_set_with_capacity(length);
+            let mut path = index_set_with_capacity(length);
+
+            verified_keys.insert(root_key);
+
+            for key in parent_pointers.keys() {
+                let mut current_key = key;
+
+                while !verified_keys.contains(&current_key) {
+                    if !path.insert(current_key) {
+                        return Err(BuildError::Cycle { key: current_key });
+                    };
+
+                    current_key = parent_pointers.get(current_key).unwrap().0.unwrap();
+                }
+
+                verified_keys.extend(path.drain(..));
+            }
+
+            Ok(root_key)
+        }
+
+        let old_root_key = validate(&parent_pointers)?;
+
+        let length = parent_pointers.len();
+        let mut inner_nodes = SlotMap::with_capacity_and_key(length);
+        let mut old_parent_keys = Vec::with_capacity(length);
+        let mut key_map = SecondaryMap::with_capacity(length);
+
+        for (old_key, (old_parent_key, value)) in parent_pointers {
+            let key = inner_nodes.insert(InnerNode {
+                parent_key: None,
+                child_keys: IndexSet::default(),
+                value,
+            });
+            old_parent_keys.push((key, old_parent_key));
+            key_map.insert(old_key, key);
+        }
+
+        for (key, old_parent_key) in old_parent_keys {
+            if let Some(old_parent_key) = old_parent_key {
+                let parent_key = *key_map.get(old_parent_key).unwrap();
+
+                inner_nodes.get_mut(key).unwrap().parent_key = Some(parent_key);
+                inner_nodes
+                    .get_mut(parent_key)
+                    .unwrap()
+                    .child_keys
+                    .insert(key);
+            };
+        }
+
+        let tree = Self {
+            root_key: Some(*key_map.get(old_root_key).unwrap()),
+            inner_nodes,
+        };
+
+        Ok((tree, key_map))
+    }
+
+    /// Consumes this [`Tree`] instance and converts it into a [`SlotMap`] of
+    /// `(parent_key, value)` pairs.
+    ///
+    /// This is the inverse of [`Tree::from_parent_pointers`]. The values are
+    /// inserted into the new [`SlotMap`] in breadth-first order, so that
+    /// [`Tree::from_parent_pointers`] rebuilds the children of each value in
+    /// their original order.
+    ///
+    /// Alongside the new [`SlotMap`], a [`SecondaryMap`] is returned which maps
+    /// each key of this [`Tree`] instance to its corresponding key inside of
+    /// the new [`SlotMap`].
+    ///
+    /// # Note:
+    /// A [`SlotMap`] mints its own keys upon insertion. Therefore, the keys of
+    /// the returned [`SlotMap`] will generally *not* line up with the keys of
+    /// this [`Tree`] instance (e.g., if any value was ever removed from it, or
+    /// if its children were ever reordered). Always translate the old keys
+    /// through the returned [`SecondaryMap`].
+    pub fn into_parent_pointers(mut self) -> (ParentPointers<K, V>, SecondaryMap<K, K>) {
+        let length = self.inner_nodes.len();
+        let mut parent_pointers = SlotMap::with_capacity_and_key(length);
+        let mut key_map = SecondaryMap::with_capacity(length);
+
+        let old_keys = self
+            .bfs_with_parent()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+
+        for old_key in old_keys {
+            let inner_node = self.inner_nodes.remove(old_key).unwrap();
+            let parent_key = inner_node
+                .parent_key
+                .map(|old_parent_key| *key_map.get(old_parent_key).unwrap());
+
+            let key = parent_pointers.insert((parent_key, inner_node.value));
+            key_map.insert(old_key, key);
+        }
+
+        (parent_pointers, key_map)
+    }
+
+    /// Creates a new [`Tree`] instance from a flat list of `(value,
+    /// parent_index)` records, in which `parent_index` is the position of the
+    /// record's parent inside of `records`.
+    ///
+    /// Exactly one record (the root) must have a `parent_index` of [`None`],
+    /// and every other record must refer to a parent which *precedes* it. As
+    /// such, the root must always be the first record. The order of the
+    /// records is preserved in the order of each value's children.
+    ///
+    /// If `records` does not contain exactly one root, or if any
+    /// `parent_index` is dangling or forward-referencing, then [`None`] is
+    /// returned. Otherwise, returns [`Some(..)`] containing the new [`Tree`].
+    pub fn from_parented<I>(records: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (V, Option<usize>)>,
+    {
+        let records = records.into_iter();
+        let (lower_bound, _) = records.size_hint();
+
+        let mut tree = Self::with_capacity(lower_bound);
+        let mut keys = Vec::with_capacity(lower_bound);
+
+        for (index, (value, parent_index)) in records.enumerate() {
+            let key = match parent_index {
+                None if index == 0 => tree.insert_root(value),
+                Some(parent_index) if parent_index < index => {
+                    tree.insert(value, keys[parent_index]).unwrap()
+                }
+                _ => return None,
+            };
+            keys.push(key);
+        }
+
+        tree.root_key.is_some().then_some(tree)
+    }
+
+    /// Creates a new [`Tree`] instance containing clones of all the values of
+    /// this [`Tree`] instance, redistributed into a *complete*
+    /// `branching`-ary tree.
+    ///
+    /// The values are collected in preorder and then laid out level by level
+    /// (i.e., the first value becomes the root, the next `branching` values
+    /// become its children, and so on), such that every level except for
+    /// possibly the last is completely filled.
+    ///
+    /// # Note:
+    /// The parent/child relationships of this [`Tree`] instance are *not*
+    /// preserved; only the values and their (preorder) ordering are. The
+    /// returned [`Tree`] instance will also have its own, new keys.
+    ///
+    /// # Panics:
+    /// Panics if `branching` is `0` while this [`Tree`] instance contains more
+    /// than one value (since no value could then have any children).
+    pub fn rebuild_complete(&self, branching: usize) -> Tree<K, V>
+    where
+        V: Clone,
+    {
+        let length = self.inner_nodes.len();
+
+        assert!(
+            branching > 0 || length <= 1,
+            "cannot build a complete tree with a branching factor of 0"
+        );
+
+        let mut values = Vec::with_capacity(length);
+        let mut to_visit_keys = Vec::with_capacity(length);
+        to_visit_keys.extend(self.root_key);
+
+        while let Some(to_visit_key) = to_visit_keys.pop() {
+            let inner_node = self.inner_nodes.get(to_visit_key).unwrap();
+            values.push(inner_node.value.clone());
+            to_visit_keys.extend(inner_node.child_keys.iter().rev());
+        }
+
+        let get_capacity = |index: usize| {
+            let first_child_index = index.saturating_mul(branching).saturating_add(1);
+            length.saturating_sub(first_child_index).min(branching)
+        };
+
+        let mut tree = Tree::with_capacity(length);
+        let mut keys = Vec::with_capacity(length);
+
+        for (index, value) in values.into_iter().enumerate() {
+            let key = match index {
+                0 => tree.insert_root_with_capacity(value, get_capacity(index)),
+                _ => {
+                    let parent_key = keys[(index - 1) / branching];
+                    tree.insert_with_capacity(value, parent_key, get_capacity(index))
+                        .unwrap()
+                }
+            };
+            keys.push(key);
+        }
+
+        tree
+    }
+
+    /// Creates a new [`Tree`] instance with the same structure as this [`Tree`]
+    /// instance, in which equal values are *interned* (i.e., all share the
+    /// same [`Rc`] allocation).
+    ///
+    /// Returns the new [`Tree`] instance along with the number of unique values
+    /// that it contains.
+    ///
+    /// The returned [`Tree`] instance will have its own, new keys.
+    ///
+    /// # Note:
+    /// When the `std` feature is enabled, the values are hashed with a randomly
+    /// seeded `std::collections::hash_map::RandomState`.
+    /// Otherwise, they are hashed with [`KeyHasher`], which is *not* resistant
+    /// against HashDoS attacks; use [`Tree::intern_with_hasher`] to pick a
+    /// different hasher.
+    pub fn intern(&self) -> (Tree<K, Rc<V>>, usize)
+    where
+        V: Clone + Eq + Hash,
+    {
+        #[cfg(feature = "std")]
+        let hash_builder = std::collections::hash_map::RandomState::new();
+
+        #[cfg(not(feature = "std"))]
+        let hash_builder = KeyBuildHasher::default();
+
+        self.intern_with_hasher(hash_builder)
+    }
+
+    /// Creates a new [`Tree`] instance with the same structure as this [`Tree`]
+    /// instance, in which equal values are *interned* (i.e., all share the
+    /// same [`Rc`] allocation).
+    ///
+    /// This behaves exactly like [`Tree::intern`], except that the values are
+    /// deduplicated using hashers built by the given `hash_builder`.
+    pub fn intern_with_hasher<S>(&self, hash_builder: S) -> (Tree<K, Rc<V>>, usize)
+    where
+        V: Clone + Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut interned_values =
+            IndexSet::<Rc<V>, S>::with_capacity_and_hasher(self.inner_nodes.len(), hash_builder);
+
+        let tree = into_ok(self.map_structure(|_, value| {
+            let interned_value = match interned_values.get(value) {
+                Some(interned_value) => interned_value.clone(),
+                None => {
+                    let interned_value = Rc::new(value.clone());
+                    interned_values.insert(interned_value.clone());
+                    interned_value
+                }
+            };
+
+            Ok::<_, Infallible>(interned_value)
+        }));
+
+        (tree, interned_values.len())
+    }
+
+    /// Creates a new [`Tree`] instance with the same structure as this [`Tree`]
+    /// instance (including the order of all children), in which each value is
+    /// the result of calling `f` on the corresponding value of this [`Tree`]
+    /// instance.
+    ///
+    /// The returned [`Tree`] instance will have its own, new keys. If you need
+    /// to correlate the old keys with the new values, use
+    /// [`Tree::map_with_key`] instead.
+    pub fn map<U, F>(&self, mut f: F) -> Tree<K, U>
+    where
+        F: FnMut(&V) -> U,
+    {
+        self.map_with_key(|_, value| f(value))
+    }
+
+    /// Creates a new [`Tree`] instance with the same structure as this [`Tree`]
+    /// instance (including the order of all children), in which each value is
+    /// the result of calling `f` on the corresponding key and value of this
+    /// [`Tree`] instance.
+    ///
+    /// The returned [`Tree`] instance will have its own, new keys.
+    pub fn map_with_key<U, F>(&self, mut f: F) -> Tree<K, U>
+    where
+        F: FnMut(K, &V) -> U,
+    {
+        into_ok(self.map_structure(|key, value| Ok::<_, Infallible>(f(key, value))))
+    }
+
+    /// Creates a new [`Tree`] instance with the same structure as this [`Tree`]
+    /// instance (including the order of all children), in which each value is
+    /// the result of calling the fallible `f` on the corresponding value of
+    /// this [`Tree`] instance.
+    ///
+    /// If `f` fails on any value, then the construction is aborted and the
+    /// first error encountered is returned (no partially constructed [`Tree`]
+    /// is ever handed back). Otherwise, returns the new [`Tree`] instance,
+    /// which will have its own, new keys.
+    pub fn try_map<U, E, F>(&self, mut f: F) -> Result<Tree<K, U>, E>
+    where
+        F: FnMut(&V) -> Result<U, E>,
+    {
+        self.map_structure(|_, value| f(value))
+    }
+
+    /// Creates a new [`Tree`] instance containing a deep copy of the subtree
+    /// rooted at the given `key` (including the order of all children).
+    ///
+    /// The returned [`Tree`] has fresh keys which are unrelated to the keys of
+    /// this [`Tree`] instance; this [`Tree`] instance is left untouched.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// cloned subtree.
+    pub fn clone_subtree(&self, key: K) -> Option<Tree<K, V>>
+    where
+        V: Clone,
+    {
+        self.inner_nodes.contains_key(key).then(|| {
+            into_ok(
+                self.map_subtree_structure(key, 0, |_, value| Ok::<_, Infallible>(value.clone())),
+            )
+        })
+    }
+
     // Check methods:
 
     /// Checks whether or not this [`Tree`] instance has the given `key` inside
@@ -92,6 +517,206 @@ where
         self.inner_nodes.is_empty()
     }
 
+    /// Checks whether or not the given `key` is a leaf (i.e., has no
+    /// children).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// result of the check.
+    pub fn is_leaf(&self, key: K) -> Option<bool> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| inner_node.child_keys.is_empty())
+    }
+
+    /// Checks whether or not the given `key` is the root (i.e., has no
+    /// parent).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// result of the check.
+    pub fn is_root(&self, key: K) -> Option<bool> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| inner_node.parent_key.is_none())
+    }
+
+    /// Checks whether or not `maybe_ancestor` is a (strict) ancestor of
+    /// `maybe_descendant` (i.e., whether `maybe_ancestor` can be found by
+    /// traversing up the parental lineage of `maybe_descendant`).
+    ///
+    /// A key is *not* considered to be its own ancestor.
+    ///
+    /// If either `maybe_ancestor` or `maybe_descendant` do not exist in this
+    /// [`Tree`] instance, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the result of the check.
+    pub fn is_ancestor(&self, maybe_ancestor: K, maybe_descendant: K) -> Option<bool> {
+        self.get_relationship(maybe_ancestor, maybe_descendant)
+            .map(|relationship| {
+                matches!(
+                    relationship,
+                    Relationship::Ancestral { ancestor_key, .. } if ancestor_key == maybe_ancestor,
+                )
+            })
+    }
+
+    /// Checks whether or not `maybe_descendant` is a (strict) descendent of
+    /// `maybe_ancestor`.
+    ///
+    /// This is the mirror image of [`Tree::is_ancestor`]. A key is *not*
+    /// considered to be its own descendent.
+    ///
+    /// If either `maybe_descendant` or `maybe_ancestor` do not exist in this
+    /// [`Tree`] instance, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the result of the check.
+    pub fn is_descendant(&self, maybe_descendant: K, maybe_ancestor: K) -> Option<bool> {
+        self.is_ancestor(maybe_ancestor, maybe_descendant)
+    }
+
+    /// Checks whether or not this [`Tree`] instance contains a value which is
+    /// equal to the given `value`.
+    pub fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.key_of(value).is_some()
+    }
+
+    /// Checks whether or not this [`Tree`] instance contains a cycle (i.e., a
+    /// key which is its own ancestor).
+    ///
+    /// The public APIs of [`Tree`] never introduce cycles, so this is mainly
+    /// useful as a sanity check.
+    ///
+    /// If a cycle is found, then [`Some(..)`] is returned containing a key
+    /// which lies on that cycle. Otherwise, returns [`None`].
+    pub fn detect_cycle(&self) -> Option<K> {
+        let length = self.inner_nodes.len();
+        let mut verified_keys = index_set_with_capacity(length);
+        let mut path = index_set_with_capacity(length);
+
+        for key in self.inner_nodes.keys() {
+            let mut current_key = Some(key);
+
+            while let Some(key) = current_key {
+                if verified_keys.contains(&key) {
+                    break;
+                };
+
+                if !path.insert(key) {
+                    return Some(key);
+                };
+
+                current_key = self
+                    .inner_nodes
+                    .get(key)
+                    .and_then(|inner_node| inner_node.parent_key);
+            }
+
+            verified_keys.extend(path.drain(..));
+        }
+
+        None
+    }
+
+    /// Checks that the internal invariants of this [`Tree`] instance hold.
+    ///
+    /// Namely, at most one value has no parent and its key is the `root_key`
+    /// (and the `root_key` is [`None`] iff this [`Tree`] instance is empty),
+    /// every key inside of a `child_keys` set exists, every `parent_key`
+    /// exists, every value's `parent_key` agrees with the `child_keys` of its
+    /// parent (and vice-versa), and there are no cycles.
+    ///
+    /// The public APIs of [`Tree`] never break these invariants, so this is
+    /// mainly useful as a sanity check (e.g., as a post-condition in tests).
+    ///
+    /// If an invariant is broken, then the corresponding [`TreeError`] is
+    /// returned. Otherwise, returns [`Ok(())`].
+    pub fn validate(&self) -> Result<(), TreeError<K>> {
+        let mut parentless_key = None;
+
+        for (key, inner_node) in &self.inner_nodes {
+            match inner_node.parent_key {
+                Some(parent_key) => match self.inner_nodes.get(parent_key) {
+                    Some(parent_inner_node) if parent_inner_node.child_keys.contains(&key) => (),
+                    Some(..) => return Err(TreeError::ParentMismatch { key, parent_key }),
+                    None => return Err(TreeError::MissingParent { key, parent_key }),
+                },
+                None if parentless_key.is_some() => return Err(TreeError::MultipleRoots),
+                None => parentless_key = Some(key),
+            }
+
+            for &child_key in &inner_node.child_keys {
+                match self.inner_nodes.get(child_key) {
+                    Some(child_inner_node) if child_inner_node.parent_key == Some(key) => (),
+                    Some(..) => {
+                        return Err(TreeError::ParentMismatch {
+                            key: child_key,
+                            parent_key: key,
+                        })
+                    }
+                    None => return Err(TreeError::MissingChild { key, child_key }),
+                }
+            }
+        }
+
+        if parentless_key != self.root_key {
+            return Err(TreeError::RootMismatch {
+                root_key: self.root_key,
+            });
+        };
+
+        match self.detect_cycle() {
+            Some(key) => Err(TreeError::Cycle { key }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks whether or not this [`Tree`] instance and `other` have the same
+    /// shape and equal values at corresponding positions.
+    ///
+    /// Children are compared in the order in which they appear inside of
+    /// their parent's `child_keys`. The keys themselves are *not* compared,
+    /// which means that two [`Tree`]s built independently of each other can
+    /// still be structurally equal.
+    pub fn structurally_eq(&self, other: &Tree<K, V>) -> bool
+    where
+        V: PartialEq,
+    {
+        if self.inner_nodes.len() != other.inner_nodes.len() {
+            return false;
+        };
+
+        match (self.root_key, other.root_key) {
+            (Some(root_key), Some(other_root_key)) => {
+                let mut to_visit_keys = vec![(root_key, other_root_key)];
+
+                while let Some((key, other_key)) = to_visit_keys.pop() {
+                    let inner_node = self.inner_nodes.get(key).unwrap();
+                    let other_inner_node = other.inner_nodes.get(other_key).unwrap();
+
+                    if inner_node.value != other_inner_node.value
+                        || inner_node.child_keys.len() != other_inner_node.child_keys.len()
+                    {
+                        return false;
+                    };
+
+                    to_visit_keys.extend(
+                        inner_node
+                            .child_keys
+                            .iter()
+                            .copied()
+                            .zip(other_inner_node.child_keys.iter().copied()),
+                    );
+                }
+
+                true
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
     // Insertion/removal methods:
 
     /// Inserts a new root value into this [`Tree`] instance.
@@ -116,7 +741,7 @@ where
 
         let root_key = self.inner_nodes.insert(InnerNode {
             parent_key: None,
-            child_keys: IndexSet::with_capacity(capacity),
+            child_keys: index_set_with_capacity(capacity),
             value,
         });
         self.root_key = Some(root_key);
@@ -124,6 +749,53 @@ where
         root_key
     }
 
+    /// Inserts a new root value into this [`Tree`] instance, but *only* if this
+    /// [`Tree`] instance is empty.
+    ///
+    /// Unlike [`Tree::insert_root`], an existing [`Tree`] is never cleared.
+    ///
+    /// If this [`Tree`] instance already contains a root value, then
+    /// [`Err(..)`] containing the given `value` is returned and no updates to
+    /// the [`Tree`] are made. Otherwise, returns [`Ok(..)`] containing the key
+    /// corresponding to the new root value.
+    pub fn try_insert_root(&mut self, value: V) -> Result<K, V> {
+        if self.root_key.is_some() {
+            Err(value)
+        }
+        else {
+            Ok(self.insert_root(value))
+        }
+    }
+
+    /// Inserts a new root value into this [`Tree`] instance *above* the current
+    /// root value.
+    ///
+    /// Unlike [`Tree::insert_root`], this [`Tree`] instance is *not* cleared.
+    /// Instead, the current root value becomes the sole child of the new root
+    /// value. If this [`Tree`] instance is empty, then this is equivalent to
+    /// [`Tree::insert_root`].
+    ///
+    /// Returns the key corresponding to the new root value.
+    pub fn prepend_root(&mut self, value: V) -> K {
+        match self.root_key {
+            Some(old_root_key) => {
+                let mut child_keys = index_set_with_capacity(1);
+                child_keys.insert(old_root_key);
+
+                let root_key = self.inner_nodes.insert(InnerNode {
+                    parent_key: None,
+                    child_keys,
+                    value,
+                });
+                self.inner_nodes.get_mut(old_root_key).unwrap().parent_key = Some(root_key);
+                self.root_key = Some(root_key);
+
+                root_key
+            }
+            None => self.insert_root(value),
+        }
+    }
+
     /// Inserts a new child value into this [`Tree`] instance.
     ///
     /// If this [`Tree`] instance does not contain the given `parent_key`, then
@@ -150,7 +822,7 @@ where
 
             let key = self.inner_nodes.insert(InnerNode {
                 parent_key: Some(parent_key),
-                child_keys: IndexSet::with_capacity(capacity),
+                child_keys: index_set_with_capacity(capacity),
                 value,
             });
 
@@ -164,27 +836,98 @@ where
         })
     }
 
-    /// Reorder the children of the given `key` in this [`Tree`] instance.
+    /// Inserts a new value into this [`Tree`] instance as a sibling of the
+    /// given `sibling_key`, positioned immediately *before* it amongst its
+    /// parent's children.
     ///
-    /// This function accepts a closure, `get_reordered_keys`, which passes in
-    /// the current children of the given `key`. The closure is then
-    /// expected to return a new [`IndexSet`] containing the original keys
-    /// in the specified order that the caller would like.
+    /// If this [`Tree`] instance does not contain the given `sibling_key`, or
+    /// if `sibling_key` is the root key, then [`None`] is returned. Otherwise,
+    /// returns [`Some(..)`] containing the new key corresponding to this new
+    /// value.
+    pub fn insert_before(&mut self, value: V, sibling_key: K) -> Option<K> {
+        self.insert_next_to(value, sibling_key, 0)
+    }
+
+    /// Inserts a new value into this [`Tree`] instance as a sibling of the
+    /// given `sibling_key`, positioned immediately *after* it amongst its
+    /// parent's children.
     ///
-    /// # Note:
-    /// Callers must ensure that `get_reordered_keys` returns a [`IndexSet`]
-    /// that is a *strict* subseteq of the current child keys. If
-    /// `get_reordered_keys` returns an [`IndexSet`] that contains at least one
-    /// key that was not contained in the original child keys, then this
-    /// function will return `false`.
+    /// If this [`Tree`] instance does not contain the given `sibling_key`, or
+    /// if `sibling_key` is the root key, then [`None`] is returned. Otherwise,
+    /// returns [`Some(..)`] containing the new key corresponding to this new
+    /// value.
+    pub fn insert_after(&mut self, value: V, sibling_key: K) -> Option<K> {
+        self.insert_next_to(value, sibling_key, 1)
+    }
+
+    /// Inserts each of the given `values` as a new child of the given
+    /// `parent_key`, in iteration order (i.e., the values are appended to the
+    /// end of `parent_key`'s children).
     ///
-    /// However, returning an [`IndexSet`] that is *missing* a few keys from the
-    /// original child keys is fine. This function will interpret that
-    /// situation as the caller requesting to have those keys removed from
-    /// this [`Tree`] instance.
+    /// The lower bound of the iterator's size hint is used to reserve space up
+    /// front, both for the new values and for `parent_key`'s children.
+    ///
+    /// If the given `parent_key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned and no values are inserted. Otherwise, returns
+    /// [`Some(..)`] containing the new keys (in the same order as `values`).
+    pub fn insert_many<I>(&mut self, parent_key: K, values: I) -> Option<Vec<K>>
+    where
+        I: IntoIterator<Item = V>,
+    {
+        self.inner_nodes.contains_key(parent_key).then(|| {
+            let values = values.into_iter();
+            let (lower_bound, _) = values.size_hint();
+
+            self.inner_nodes.reserve(lower_bound);
+            self.inner_nodes
+                .get_mut(parent_key)
+                .unwrap()
+                .child_keys
+                .reserve(lower_bound);
+
+            let mut keys = Vec::with_capacity(lower_bound);
+
+            for value in values {
+                let key = self.inner_nodes.insert(InnerNode {
+                    parent_key: Some(parent_key),
+                    child_keys: IndexSet::default(),
+                    value,
+                });
+
+                self.inner_nodes
+                    .get_mut(parent_key)
+                    .unwrap()
+                    .child_keys
+                    .insert(key);
+
+                keys.push(key);
+            }
+
+            keys
+        })
+    }
+
+    /// Reorder the children of the given `key` in this [`Tree`] instance.
+    ///
+    /// This function accepts a closure, `get_reordered_keys`, which passes in
+    /// the current children of the given `key`. The closure is then
+    /// expected to return a new [`IndexSet`] containing the original keys
+    /// in the specified order that the caller would like.
+    ///
+    /// # Note:
+    /// Callers must ensure that `get_reordered_keys` returns a [`IndexSet`]
+    /// that is a *strict* subseteq of the current child keys. If
+    /// `get_reordered_keys` returns an [`IndexSet`] that contains at least one
+    /// key that was not contained in the original child keys, then this
+    /// function will return `false`.
+    ///
+    /// However, returning an [`IndexSet`] that is *missing* a few keys from the
+    /// original child keys is fine. This function will interpret that
+    /// situation as the caller requesting to have those keys removed from
+    /// this [`Tree`] instance.
     pub fn reorder_children<F>(&mut self, key: K, get_reordered_keys: F) -> bool
     where
-        F: FnOnce(&IndexSet<K>) -> IndexSet<K>,
+        F: FnOnce(&KeySet<K>) -> KeySet<K>,
     {
         self.inner_nodes
             .get(key)
@@ -227,6 +970,95 @@ where
             .is_some()
     }
 
+    /// Sorts the children of the given `key` by comparing their values with
+    /// `compare`.
+    ///
+    /// The sort is stable and never removes any children (unlike
+    /// [`Tree::reorder_children`]); it only reorders them.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// `false` is returned and no updates to the [`Tree`] are made. Otherwise,
+    /// performs the sort and returns `true`.
+    pub fn sort_children_by<F>(&mut self, key: K, mut compare: F) -> bool
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        self.inner_nodes
+            .get_mut(key)
+            .map(|inner_node| take(&mut inner_node.child_keys))
+            .map(|mut child_keys| {
+                child_keys.sort_by(|&child_key_1, &child_key_2| {
+                    let value_1 = &self.inner_nodes.get(child_key_1).unwrap().value;
+                    let value_2 = &self.inner_nodes.get(child_key_2).unwrap().value;
+                    compare(value_1, value_2)
+                });
+
+                self.inner_nodes.get_mut(key).unwrap().child_keys = child_keys;
+            })
+            .is_some()
+    }
+
+    /// Sorts the children of the given `key` by the key that `f` extracts from
+    /// each of their values.
+    ///
+    /// The sort is stable (i.e., children with equal sort keys retain their
+    /// relative order) and never removes any children (unlike
+    /// [`Tree::reorder_children`]); it only reorders them.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// `false` is returned and no updates to the [`Tree`] are made. Otherwise,
+    /// performs the sort and returns `true`.
+    pub fn sort_children_by_key<B, F>(&mut self, key: K, mut f: F) -> bool
+    where
+        F: FnMut(&V) -> B,
+        B: Ord,
+    {
+        self.sort_children_by(key, |value_1, value_2| f(value_1).cmp(&f(value_2)))
+    }
+
+    /// Moves the given `key` to the given `new_index` amongst its siblings
+    /// (i.e., inside of its parent's `child_keys`), shifting the siblings in
+    /// between over by one.
+    ///
+    /// If `new_index` is out of bounds, then it is clamped to the last valid
+    /// index (i.e., `key` is moved to the back).
+    ///
+    /// If `key` was not found in this [`Tree`] instance, or if `key` is the
+    /// root key, then `false` is returned and no updates to the [`Tree`] are
+    /// made. Otherwise, performs the move and returns `true`.
+    pub fn move_child_to_index(&mut self, key: K, new_index: usize) -> bool {
+        self.inner_nodes
+            .get(key)
+            .and_then(|inner_node| inner_node.parent_key)
+            .map(|parent_key| {
+                let child_keys = &mut self.inner_nodes.get_mut(parent_key).unwrap().child_keys;
+                let index = child_keys.get_index_of(&key).unwrap();
+                let new_index = new_index.min(child_keys.len() - 1);
+                child_keys.move_index(index, new_index);
+            })
+            .is_some()
+    }
+
+    /// Moves the given `key` to the front of its siblings (i.e., to the first
+    /// index inside of its parent's `child_keys`).
+    ///
+    /// If `key` was not found in this [`Tree`] instance, or if `key` is the
+    /// root key, then `false` is returned and no updates to the [`Tree`] are
+    /// made. Otherwise, performs the move and returns `true`.
+    pub fn move_child_to_front(&mut self, key: K) -> bool {
+        self.move_child_to_index(key, 0)
+    }
+
+    /// Moves the given `key` to the back of its siblings (i.e., to the last
+    /// index inside of its parent's `child_keys`).
+    ///
+    /// If `key` was not found in this [`Tree`] instance, or if `key` is the
+    /// root key, then `false` is returned and no updates to the [`Tree`] are
+    /// made. Otherwise, performs the move and returns `true`.
+    pub fn move_child_to_back(&mut self, key: K) -> bool {
+        self.move_child_to_index(key, usize::MAX)
+    }
+
     /// Removes the value corresponding to the given `key` from this [`Tree`]
     /// instance as well as *all* of its children values.
     ///
@@ -241,54 +1073,280 @@ where
     ///
     /// If you do not have a hint, then provide [`None`] as the argument.
     pub fn remove(&mut self, key: K, size_hint: Option<usize>) -> Option<V> {
-        fn remove_root<K, V>(tree: &mut Tree<K, V>, root_key: K) -> V
-        where
-            K: Key,
-        {
-            let value = tree.inner_nodes.remove(root_key).unwrap().value;
-            tree.clear();
-            value
-        }
+        self.remove_subtree(key, size_hint)
+            .map(|inner_node| inner_node.value)
+    }
 
-        fn remove_non_root<K, V>(
-            tree: &mut Tree<K, V>,
-            key: K,
-            size_hint: Option<usize>,
-        ) -> Option<V>
-        where
-            K: Key,
-        {
-            tree.inner_nodes.remove(key).map(|inner_node| {
-                let size_hint = size_hint.unwrap_or_else(|| tree.inner_nodes.len());
+    /// Removes the value corresponding to the given `key` from this [`Tree`]
+    /// instance, but *only* if `key` is a leaf (i.e., has no children).
+    ///
+    /// Unlike [`Tree::remove`], this never removes any other values.
+    ///
+    /// If this [`Tree`] instance does not contain the given `key`, or if `key`
+    /// has children, then [`None`] is returned and no updates to the [`Tree`]
+    /// are made. Otherwise, returns [`Some(..)`] containing the removed value.
+    pub fn take_leaf(&mut self, key: K) -> Option<V> {
+        self.is_leaf(key)
+            .unwrap_or(false)
+            .then(|| self.remove(key, Some(0)).unwrap())
+    }
+
+    /// Removes *all* the descendents of the given `key` from this [`Tree`]
+    /// instance, while keeping the value corresponding to `key` itself (i.e.,
+    /// `key` becomes a leaf).
+    ///
+    /// If this [`Tree`] instance does not contain the given `key`, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// number of removed values.
+    ///
+    /// The `size_hint` argument allows for one to specify the number of
+    /// descendents the given `key` has. This can be helpful in order
+    /// to allocate only the necessary amount of space and to avoid
+    /// additional allocations + `memcpy`'s.
+    ///
+    /// If you do not have a hint, then provide [`None`] as the argument.
+    pub fn remove_children(&mut self, key: K, size_hint: Option<usize>) -> Option<usize> {
+        let size_hint = size_hint.unwrap_or_else(|| self.inner_nodes.len());
 
+        self.inner_nodes
+            .get_mut(key)
+            .map(|inner_node| {
                 let mut to_visit_keys = Vec::with_capacity(size_hint);
-                to_visit_keys.extend(inner_node.child_keys);
+                to_visit_keys.extend(inner_node.child_keys.drain(..));
+                to_visit_keys
+            })
+            .map(|mut to_visit_keys| {
+                let mut removed_count = 0;
 
                 while let Some(to_visit_key) = to_visit_keys.pop() {
-                    let inner_node = tree.inner_nodes.remove(to_visit_key).unwrap();
+                    let inner_node = self.inner_nodes.remove(to_visit_key).unwrap();
                     to_visit_keys.extend(inner_node.child_keys);
+                    removed_count += 1;
+                }
+
+                removed_count
+            })
+    }
+
+    /// Removes *only* the value corresponding to the given `key` from this
+    /// [`Tree`] instance, splicing its children up into its parent.
+    ///
+    /// The children of `key` are appended (in order) to the end of the
+    /// `child_keys` of `key`'s parent. If `key` is the root key, then its only
+    /// child (if any) becomes the new root.
+    ///
+    /// If this [`Tree`] instance does not contain the given `key`, or if `key`
+    /// is the root key and has more than one child (since a [`Tree`] cannot
+    /// have multiple roots), then [`None`] is returned and no updates to the
+    /// [`Tree`] are made. Otherwise, returns [`Some(..)`] containing the
+    /// removed value.
+    pub fn remove_and_reparent(&mut self, key: K) -> Option<V> {
+        let inner_node = self.inner_nodes.get(key)?;
+
+        match inner_node.parent_key {
+            Some(parent_key) => {
+                let inner_node = self.inner_nodes.remove(key).unwrap();
+
+                for &child_key in &inner_node.child_keys {
+                    self.inner_nodes.get_mut(child_key).unwrap().parent_key = Some(parent_key);
+                }
+
+                let parent_child_keys =
+                    &mut self.inner_nodes.get_mut(parent_key).unwrap().child_keys;
+                parent_child_keys.shift_remove(&key);
+                parent_child_keys.extend(inner_node.child_keys);
+
+                Some(inner_node.value)
+            }
+            None if inner_node.child_keys.len() <= 1 => {
+                let inner_node = self.inner_nodes.remove(key).unwrap();
+                self.root_key = inner_node.child_keys.first().copied();
+
+                if let Some(new_root_key) = self.root_key {
+                    self.inner_nodes.get_mut(new_root_key).unwrap().parent_key = None;
+                };
+
+                Some(inner_node.value)
+            }
+            None => None,
+        }
+    }
+
+    /// Deletes the value corresponding to the given `key` from this [`Tree`]
+    /// instance as well as *all* of its children values.
+    ///
+    /// Unlike [`Tree::remove`], the removed values are discarded instead of
+    /// being returned to the caller.
+    ///
+    /// If this [`Tree`] instance does not contain the given `key`, then `false`
+    /// is returned. Otherwise, performs the deletion and returns `true`.
+    pub fn delete(&mut self, key: K) -> bool {
+        self.remove_subtree(key, None).is_some()
+    }
+
+    /// Retains only the values for which the given predicate, `f`, returns
+    /// `true`.
+    ///
+    /// When a value fails the predicate, it is removed along with *all* of its
+    /// descendents (regardless of whether or not they would have passed the
+    /// predicate). Parents are always visited before their children, so `f`
+    /// is never called on the descendents of a removed value. If the root
+    /// value fails the predicate, then this [`Tree`] instance is cleared.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K, &V) -> bool,
+    {
+        if let Some(root_key) = self.root_key {
+            if f(root_key, &self.inner_nodes.get(root_key).unwrap().value) {
+                let mut to_visit_keys = vec![root_key];
+                let mut to_remove_keys = vec![];
+
+                while let Some(to_visit_key) = to_visit_keys.pop() {
+                    let mut child_keys =
+                        take(&mut self.inner_nodes.get_mut(to_visit_key).unwrap().child_keys);
+
+                    child_keys.retain(|&child_key| {
+                        let keep = f(child_key, &self.inner_nodes.get(child_key).unwrap().value);
+
+                        if !keep {
+                            to_remove_keys.push(child_key);
+                        };
+
+                        keep
+                    });
+
+                    to_visit_keys.extend(&child_keys);
+                    self.inner_nodes.get_mut(to_visit_key).unwrap().child_keys = child_keys;
+                }
+
+                while let Some(to_remove_key) = to_remove_keys.pop() {
+                    let inner_node = self.inner_nodes.remove(to_remove_key).unwrap();
+                    to_remove_keys.extend(inner_node.child_keys);
                 }
+            }
+            else {
+                self.clear();
+            };
+        };
+    }
 
+    /// Removes the subtree rooted at the given `key` from this [`Tree`]
+    /// instance and returns it as a new [`Tree`] instance (including the order
+    /// of all children).
+    ///
+    /// If `key` is the root key, then this [`Tree`] instance is left empty and
+    /// its entire contents are returned (with their keys unchanged). Otherwise,
+    /// the returned [`Tree`] has fresh keys which are unrelated to the keys of
+    /// this [`Tree`] instance.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// detached subtree.
+    pub fn detach_subtree(&mut self, key: K) -> Option<Tree<K, V>> {
+        match self.root_key {
+            Some(root_key) if key == root_key => Some(take(self)),
+            Some(..) => self.inner_nodes.remove(key).map(|inner_node| {
                 let parent_key = inner_node.parent_key.unwrap();
-                tree.inner_nodes
+                self.inner_nodes
                     .get_mut(parent_key)
                     .unwrap()
                     .child_keys
                     .shift_remove(&key);
 
-                inner_node.value
-            })
+                let mut tree = Tree::default();
+                let new_root_key =
+                    tree.insert_root_with_capacity(inner_node.value, inner_node.child_keys.len());
+                tree.move_descendants(&mut self.inner_nodes, inner_node.child_keys, new_root_key);
+
+                tree
+            }),
+            None => None,
         }
+    }
 
-        self.root_key.and_then(|root_key| {
-            if key == root_key {
-                let root_value = remove_root(self, root_key);
-                Some(root_value)
-            }
-            else {
-                remove_non_root(self, key, size_hint)
-            }
-        })
+    /// Attaches the entire `other` [`Tree`] instance underneath the given
+    /// `parent_key`, such that the root of `other` becomes the last child of
+    /// `parent_key` (including the order of all children).
+    ///
+    /// The nodes of `other` are re-inserted into this [`Tree`] instance and are
+    /// therefore given fresh keys; the keys of `other` are not valid for this
+    /// [`Tree`] instance.
+    ///
+    /// If `parent_key` was not found in this [`Tree`] instance, then `false` is
+    /// returned and no updates to this [`Tree`] instance are made. Otherwise,
+    /// performs the graft (which is a no-op if `other` is empty) and returns
+    /// `true`. In both cases, `other` is consumed.
+    pub fn graft(&mut self, mut other: Tree<K, V>, parent_key: K) -> bool {
+        self.inner_nodes
+            .contains_key(parent_key)
+            .then(|| {
+                if let Some(other_root_key) = other.root_key {
+                    let other_root_inner_node = other.inner_nodes.remove(other_root_key).unwrap();
+                    let new_key = self
+                        .insert_with_capacity(
+                            other_root_inner_node.value,
+                            parent_key,
+                            other_root_inner_node.child_keys.len(),
+                        )
+                        .unwrap();
+                    self.move_descendants(
+                        &mut other.inner_nodes,
+                        other_root_inner_node.child_keys,
+                        new_key,
+                    );
+                }
+            })
+            .is_some()
+    }
+
+    /// Replaces the subtree rooted at the given `key` with the entire
+    /// `replacement` [`Tree`] instance, and returns the removed subtree.
+    ///
+    /// The root of `replacement` takes the exact position of `key` (i.e., it
+    /// has the same parent and the same index amongst its siblings). Both the
+    /// nodes of `replacement` and of the returned subtree are given fresh keys
+    /// (see [`Tree::graft`] and [`Tree::detach_subtree`]). If `key` is the root
+    /// key, then this [`Tree`] instance simply becomes `replacement` and its
+    /// entire previous contents are returned (with all keys unchanged). If
+    /// `replacement` is empty, then this is equivalent to
+    /// [`Tree::detach_subtree`].
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned and no updates to the [`Tree`] are made. Otherwise,
+    /// returns [`Some(..)`] containing the removed subtree.
+    pub fn replace_subtree(&mut self, key: K, replacement: Tree<K, V>) -> Option<Tree<K, V>> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| inner_node.parent_key)
+            .map(|parent_key| match parent_key {
+                Some(parent_key) => {
+                    let index = self
+                        .inner_nodes
+                        .get(parent_key)
+                        .unwrap()
+                        .child_keys
+                        .get_index_of(&key)
+                        .unwrap();
+
+                    let subtree = self.detach_subtree(key).unwrap();
+
+                    if !replacement.is_empty() {
+                        self.graft(replacement, parent_key);
+
+                        let new_key = *self
+                            .inner_nodes
+                            .get(parent_key)
+                            .unwrap()
+                            .child_keys
+                            .last()
+                            .unwrap();
+                        self.move_child_to_index(new_key, index);
+                    };
+
+                    subtree
+                }
+                None => replace(self, replacement),
+            })
     }
 
     /// Rebase the subtree rooted at `key` to be a child underneath the subtree
@@ -363,14 +1421,13 @@ where
                     alpha_node.child_keys.insert(beta_key);
 
                     let beta_parent_node = tree.inner_nodes.get_mut(beta_parent_key).unwrap();
-                    beta_parent_node.child_keys.remove(&beta_key);
-                    beta_parent_node.child_keys.insert(alpha_key);
+                    replace_child_key(&mut beta_parent_node.child_keys, beta_key, alpha_key);
 
                     tree.inner_nodes
                         .get_mut(alpha_parent_key)
                         .unwrap()
                         .child_keys
-                        .remove(&alpha_key);
+                        .shift_remove(&alpha_key);
                 }
                 None => {
                     let beta_key = key;
@@ -388,7 +1445,7 @@ where
                         .get_mut(alpha_parent_key)
                         .unwrap()
                         .child_keys
-                        .remove(&alpha_key);
+                        .shift_remove(&alpha_key);
 
                     tree.root_key = Some(alpha_key);
                 }
@@ -429,9 +1486,176 @@ where
         }
 
         self.get_relationship(key, new_parent_key)
-            .map_or(false, |relationship| {
-                rebase(self, relationship, key, new_parent_key)
+            .is_some_and(|relationship| rebase(self, relationship, key, new_parent_key))
+    }
+
+    /// Rebase the subtree rooted at `key` to be a child underneath the subtree
+    /// rooted at `new_parent_key`, returning the key of `key`'s former parent.
+    ///
+    /// This behaves exactly like [`Tree::rebase`] (including when
+    /// `new_parent_key` is a descendent of `key`). The returned key can be
+    /// recorded in order to later move `key` back to where it came from.
+    ///
+    /// # Note:
+    /// If `key` was the root key (which is only possible when rebasing onto
+    /// one of its own descendents), then it had no former parent, and [`None`]
+    /// is returned *even though* the rebase was performed. Use
+    /// [`Tree::root_key`] before calling this method if you need to tell the
+    /// two cases apart.
+    ///
+    /// If the rebase was not performed (i.e., if either key was not found in
+    /// this [`Tree`] instance, or if both keys are the same), then [`None`] is
+    /// returned and no updates to the [`Tree`] are made. Otherwise, returns
+    /// [`Some(..)`] containing the former parent key of `key`.
+    pub fn rebase_returning(&mut self, key: K, new_parent_key: K) -> Option<K> {
+        let old_parent_key = self.parent_key(key).flatten();
+
+        self.rebase(key, new_parent_key)
+            .then_some(old_parent_key)
+            .flatten()
+    }
+
+    /// Rebase the subtree rooted at `key` to be a child underneath the subtree
+    /// rooted at `new_parent_key`, placing `key` at the given `index` inside of
+    /// `new_parent_key`'s children.
+    ///
+    /// This behaves exactly like [`Tree::rebase`] (including when
+    /// `new_parent_key` is a descendent of `key`), except that instead of being
+    /// appended to the end of `new_parent_key`'s children, `key` is moved to
+    /// `index`. If `index` is out of bounds, then it is clamped to the last
+    /// valid index.
+    ///
+    /// When `new_parent_key` is a descendent of `key`, `new_parent_key` is
+    /// promoted into the exact position that `key` previously occupied among
+    /// its siblings.
+    ///
+    /// If `key` was not found in this [`Tree`] instance, then `false` is
+    /// returned and no updates to the [`Tree`] are made. Otherwise,
+    /// performs the requested rebase and returns `true`.
+    pub fn rebase_at(&mut self, key: K, new_parent_key: K, index: usize) -> bool {
+        self.rebase(key, new_parent_key) && self.move_child_to_index(key, index)
+    }
+
+    /// Makes the given `key` the new root of this [`Tree`] instance.
+    ///
+    /// Every parent-child link on the path between the old root and `key` is
+    /// inverted (i.e., each ancestor of `key` becomes a child of its former
+    /// child on that path). Each inverted parent is appended to the end of its
+    /// new parent's children; all other children keep their relative order.
+    ///
+    /// If `key` was not found in this [`Tree`] instance, then `false` is
+    /// returned and no updates to the [`Tree`] are made. Otherwise,
+    /// performs the requested re-root and returns `true`. (Re-rooting at the
+    /// current root is a no-op.)
+    pub fn reroot(&mut self, key: K) -> bool {
+        self.path_to_root(key)
+            .map(|path| {
+                for (&child_key, &parent_key) in path.iter().zip(path.iter().skip(1)) {
+                    let parent_node = self.inner_nodes.get_mut(parent_key).unwrap();
+                    parent_node.parent_key = Some(child_key);
+                    parent_node.child_keys.shift_remove(&child_key);
+
+                    let child_node = self.inner_nodes.get_mut(child_key).unwrap();
+                    child_node.child_keys.insert(parent_key);
+                }
+
+                self.inner_nodes.get_mut(key).unwrap().parent_key = None;
+                self.root_key = Some(key);
             })
+            .is_some()
+    }
+
+    /// Swaps the positions of the subtrees rooted at `key_1` and `key_2`.
+    ///
+    /// After performing this operation, `key_1` will be a child of `key_2`'s
+    /// former parent (at `key_2`'s former index amongst its siblings), and
+    /// vice-versa. The descendents of each key move along with it.
+    ///
+    /// If either key was not found in this [`Tree`] instance, or if the two
+    /// keys are the same or ancestrally related (in which case, swapping their
+    /// positions would be impossible), then `false` is returned and no
+    /// updates to the [`Tree`] are made. Otherwise, performs the requested
+    /// swap and returns `true`.
+    pub fn swap_node_positions(&mut self, key_1: K, key_2: K) -> bool {
+        match self.get_relationship(key_1, key_2) {
+            Some(Relationship::Siblings { .. }) => {
+                let parent_key_1 = self.inner_nodes.get(key_1).unwrap().parent_key.unwrap();
+                let parent_key_2 = self.inner_nodes.get(key_2).unwrap().parent_key.unwrap();
+
+                if parent_key_1 == parent_key_2 {
+                    let child_keys =
+                        &mut self.inner_nodes.get_mut(parent_key_1).unwrap().child_keys;
+                    let index_1 = child_keys.get_index_of(&key_1).unwrap();
+                    let index_2 = child_keys.get_index_of(&key_2).unwrap();
+                    child_keys.swap_indices(index_1, index_2);
+                }
+                else {
+                    let parent_node_1 = self.inner_nodes.get_mut(parent_key_1).unwrap();
+                    replace_child_key(&mut parent_node_1.child_keys, key_1, key_2);
+
+                    let parent_node_2 = self.inner_nodes.get_mut(parent_key_2).unwrap();
+                    replace_child_key(&mut parent_node_2.child_keys, key_2, key_1);
+
+                    self.inner_nodes.get_mut(key_1).unwrap().parent_key = Some(parent_key_2);
+                    self.inner_nodes.get_mut(key_2).unwrap().parent_key = Some(parent_key_1);
+                };
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Swaps the positions of the subtrees rooted at `key_1` and `key_2` (i.e.,
+    /// each subtree takes the other's parent and index amongst its siblings).
+    ///
+    /// This is an alias of [`Tree::swap_node_positions`].
+    ///
+    /// If either key was not found in this [`Tree`] instance, or if the two
+    /// keys are the same or ancestrally related, then `false` is returned and
+    /// no updates to the [`Tree`] are made. Otherwise, performs the requested
+    /// swap and returns `true`.
+    pub fn swap_subtrees(&mut self, key_1: K, key_2: K) -> bool {
+        self.swap_node_positions(key_1, key_2)
+    }
+
+    /// Moves the value corresponding to the given `key` to a new key, updating
+    /// all of the references to it (i.e., its parent's `child_keys` and its
+    /// children's `parent_key`s).
+    ///
+    /// The value keeps its position amongst its siblings.
+    ///
+    /// # Note:
+    /// A [`SlotMap`] mints its own keys upon insertion, so the new key cannot
+    /// be chosen by the caller. Instead, the value is re-inserted into this
+    /// [`Tree`] instance and the newly minted key is returned. The old `key`
+    /// is invalidated by this operation.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// new key.
+    pub fn remap_key(&mut self, key: K) -> Option<K> {
+        self.inner_nodes.remove(key).map(|inner_node| {
+            let parent_key = inner_node.parent_key;
+            let new_key = self.inner_nodes.insert(inner_node);
+
+            match parent_key {
+                Some(parent_key) => {
+                    let parent_node = self.inner_nodes.get_mut(parent_key).unwrap();
+                    replace_child_key(&mut parent_node.child_keys, key, new_key);
+                }
+                None => self.root_key = Some(new_key),
+            };
+
+            let child_keys_length = self.inner_nodes.get(new_key).unwrap().child_keys.len();
+
+            for child_index in 0..child_keys_length {
+                let child_key = self.inner_nodes.get(new_key).unwrap().child_keys[child_index];
+                self.inner_nodes.get_mut(child_key).unwrap().parent_key = Some(new_key);
+            }
+
+            new_key
+        })
     }
 
     /// Clears this [`Tree`] instance of *all* its values. Keeps the allocated
@@ -441,6 +1665,17 @@ where
         self.inner_nodes.clear();
     }
 
+    /// Clears this [`Tree`] instance of *all* its values (exactly like
+    /// [`Tree::clear`]), and then ensures that it can hold at least `capacity`
+    /// values without reallocating.
+    ///
+    /// # Panics:
+    /// Panics if the new allocation size overflows [`usize`].
+    pub fn clear_and_reserve(&mut self, capacity: usize) {
+        self.clear();
+        self.reserve(capacity);
+    }
+
     // Getter/setter methods:
 
     /// Returns the number of elements in this [`Tree`] instance.
@@ -448,6 +1683,37 @@ where
         self.inner_nodes.len()
     }
 
+    /// Returns the number of elements this [`Tree`] instance can hold without
+    /// reallocating.
+    ///
+    /// Use [`Tree::reserve`] to grow it ahead of a burst of insertions.
+    pub fn capacity(&self) -> usize {
+        self.inner_nodes.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more values to be inserted
+    /// into this [`Tree`] instance without reallocating.
+    ///
+    /// # Panics:
+    /// Panics if the new allocation size overflows [`usize`].
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner_nodes.reserve(additional);
+    }
+
+    /// Shrinks the capacity of every value's `child_keys` as much as possible.
+    ///
+    /// # Note:
+    /// The underlying [`SlotMap`] is *not* shrunk, since [`SlotMap`] does not
+    /// support releasing its slots. Each slot must be kept around (along with
+    /// its version) so that keys of removed values are never handed out again
+    /// and can never alias a newly inserted value. As such,
+    /// [`Tree::capacity`] is unaffected by this method.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner_nodes
+            .values_mut()
+            .for_each(|inner_node| inner_node.child_keys.shrink_to_fit());
+    }
+
     /// Returns the `root_key` of this [`Tree`] instance.
     ///
     /// Returns [`None`] if this [`Tree`] instance is empty. Otherwise, returns
@@ -492,11 +1758,60 @@ where
         })
     }
 
-    /// Returns a [`Node`] which corresponds to the given `key` inside of this
-    /// [`Tree`] instance.
+    /// Get a [`RootEntry`] for the root of this [`Tree`] instance, which can be
+    /// used to either access the existing root or insert a new one if this
+    /// [`Tree`] instance is empty.
+    pub fn root_entry(&mut self) -> RootEntry<'_, K, V> {
+        RootEntry { tree: self }
+    }
+
+    /// Get the parent key of the given `key`.
     ///
-    /// If the given `key` does not exist in this [`Tree`] instance, then
-    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// # Note:
+    /// The return type is a *double* [`Option`] in order to distinguish between
+    /// a missing key and the root key:
+    /// - [`None`] means that the given `key` does not exist in this [`Tree`]
+    ///   instance.
+    /// - [`Some(None)`] means that the given `key` is the root key (and
+    ///   therefore has no parent).
+    /// - [`Some(Some(..))`] contains the parent key of the given `key`.
+    ///
+    /// Use [`Option::flatten`] if you do not care about this distinction.
+    pub fn parent_key(&self, key: K) -> Option<Option<K>> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| inner_node.parent_key)
+    }
+
+    /// Get an immutable reference to the value stored at the parent of the
+    /// given `key`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, or if it is
+    /// the root key, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the parent's value.
+    pub fn parent_value(&self, key: K) -> Option<&V> {
+        self.parent_key(key)
+            .flatten()
+            .map(|parent_key| &self.inner_nodes.get(parent_key).unwrap().value)
+    }
+
+    /// Get a mutable reference to the value stored at the parent of the given
+    /// `key`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, or if it is
+    /// the root key, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the parent's value.
+    pub fn parent_value_mut(&mut self, key: K) -> Option<&mut V> {
+        self.parent_key(key)
+            .flatten()
+            .map(|parent_key| &mut self.inner_nodes.get_mut(parent_key).unwrap().value)
+    }
+
+    /// Returns a [`Node`] which corresponds to the given `key` inside of this
+    /// [`Tree`] instance.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
     /// [`Node`] entry.
     pub fn get(&self, key: K) -> Option<Node<'_, K, V>> {
         self.inner_nodes.get(key).map(|inner_node| Node {
@@ -520,6 +1835,109 @@ where
         })
     }
 
+    /// Get mutable references to the values (and some other relevant
+    /// information) corresponding to *all* of the given `keys` at once.
+    ///
+    /// If any of the given `keys` does not exist in this [`Tree`] instance, or
+    /// if `keys` contains duplicates, then [`None`] is returned. Otherwise,
+    /// returns [`Some(..)`] containing the disjoint mutable references (in
+    /// the same order as `keys`).
+    pub fn get_many_mut<const N: usize>(&mut self, keys: [K; N]) -> Option<[NodeMut<'_, K, V>; N]> {
+        self.inner_nodes.get_disjoint_mut(keys).map(|inner_nodes| {
+            inner_nodes.map(|inner_node| NodeMut {
+                parent_key: inner_node.parent_key,
+                child_keys: &inner_node.child_keys,
+                value: &mut inner_node.value,
+            })
+        })
+    }
+
+    /// Get mutable references to the value (and some other relevant
+    /// information) corresponding to the given `key`, as well as to the value
+    /// of its parent, at the same time.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, or if `key`
+    /// is the root key (and therefore has no parent), then [`None`] is
+    /// returned. Otherwise, returns [`Some(..)`] containing the mutable
+    /// references to `key`'s node and to its parent's node (in that order).
+    pub fn node_and_parent_mut(
+        &mut self,
+        key: K,
+    ) -> Option<(NodeMut<'_, K, V>, NodeMut<'_, K, V>)> {
+        self.inner_nodes
+            .get(key)
+            .and_then(|inner_node| inner_node.parent_key)
+            .and_then(|parent_key| self.get_many_mut([key, parent_key]))
+            .map(|[node, parent_node]| (node, parent_node))
+    }
+
+    /// Gets the key and [`Node`] found by starting at the root and, for each
+    /// of the given `indices`, stepping into the child at that index (by
+    /// position inside of the ordered `child_keys`).
+    ///
+    /// An empty `indices` slice refers to the root.
+    ///
+    /// If this [`Tree`] instance is empty, or if any index is out of bounds,
+    /// then [`None`] is returned. Otherwise, returns [`Some(..)`] containing
+    /// the key and an immutable reference to the node.
+    pub fn node_at_path(&self, indices: &[usize]) -> Option<(K, Node<'_, K, V>)> {
+        let mut current_key = self.root_key?;
+
+        for &index in indices {
+            let child_keys = &self.inner_nodes.get(current_key).unwrap().child_keys;
+            current_key = *child_keys.get_index(index)?;
+        }
+
+        self.get(current_key).map(|node| (current_key, node))
+    }
+
+    /// Finds the first key-value pair inside of this [`Tree`] instance whose
+    /// value satisfies the given predicate, `f`.
+    ///
+    /// The order of the search is arbitrary, so if multiple values satisfy
+    /// `f`, then it is unspecified which one is returned.
+    ///
+    /// If no value satisfies `f`, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the key and an immutable reference to the
+    /// node.
+    pub fn find<F>(&self, mut f: F) -> Option<(K, Node<'_, K, V>)>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        self.iter().find(|(_, node)| f(node.value))
+    }
+
+    /// Finds the first key-value pair inside of this [`Tree`] instance whose
+    /// value satisfies the given predicate, `f`.
+    ///
+    /// The order of the search is arbitrary, so if multiple values satisfy
+    /// `f`, then it is unspecified which one is returned.
+    ///
+    /// If no value satisfies `f`, then [`None`] is returned. Otherwise, returns
+    /// [`Some(..)`] containing the key and a mutable reference to the node.
+    pub fn find_mut<F>(&mut self, mut f: F) -> Option<(K, NodeMut<'_, K, V>)>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        self.iter_mut().find(|(_, node)| f(node.value))
+    }
+
+    /// Finds the key of the first value inside of this [`Tree`] instance which
+    /// is equal to the given `value`.
+    ///
+    /// The order of the search is arbitrary, so if multiple values are equal
+    /// to `value`, then it is unspecified which key is returned.
+    ///
+    /// If no value is equal to `value`, then [`None`] is returned. Otherwise,
+    /// returns [`Some(..)`] containing the key.
+    pub fn key_of(&self, value: &V) -> Option<K>
+    where
+        V: PartialEq,
+    {
+        self.find(|other_value| other_value == value)
+            .map(|(key, _)| key)
+    }
+
     /// Update the currently stored value at the given `key` with the
     /// `new_value` for this [`Tree`] instance.
     ///
@@ -532,110 +1950,1806 @@ where
             .map(|inner_node| replace(&mut inner_node.value, new_value))
     }
 
-    /// Gets the [`Relationship`] status between two keys.
+    /// Swaps the values at the given `key_1` and `key_2`, leaving the structure
+    /// of this [`Tree`] instance (i.e., all parent/child links) intact.
+    ///
+    /// Swapping a key with itself is a no-op.
     ///
     /// If either `key_1` or `key_2` do not exist in this [`Tree`] instance,
-    /// then [`None`] is returned. Otherwise, returns [`Some(..)`]
-    /// containing the relationship between the two keys.
-    pub fn get_relationship(&self, key_1: K, key_2: K) -> Option<Relationship<K>> {
-        fn get_relationship<K, V>(tree: &Tree<K, V>, key_1: K, key_2: K) -> Relationship<K>
-        where
-            K: Key,
-        {
-            if key_1 == key_2 {
-                Relationship::Same
-            }
-            else {
-                let mut current_parent_key = tree.inner_nodes.get(key_1).unwrap().parent_key;
-                let length = tree.inner_nodes.len();
-                let mut path = IndexSet::with_capacity(length);
+    /// then `false` is returned and no updates to the [`Tree`] are made.
+    /// Otherwise, performs the swap and returns `true`.
+    pub fn swap_values(&mut self, key_1: K, key_2: K) -> bool {
+        if key_1 == key_2 {
+            self.inner_nodes.contains_key(key_1)
+        }
+        else {
+            self.inner_nodes
+                .get_disjoint_mut([key_1, key_2])
+                .map(|[inner_node_1, inner_node_2]| {
+                    swap(&mut inner_node_1.value, &mut inner_node_2.value)
+                })
+                .is_some()
+        }
+    }
 
-                loop {
-                    match current_parent_key {
-                        Some(parent_key) if parent_key == key_2 => {
-                            return Relationship::Ancestral {
-                                ancestor_key: key_2,
-                                descendent_key: key_1,
-                            }
-                        }
-                        Some(parent_key) => {
-                            path.insert(parent_key);
-                            current_parent_key =
-                                tree.inner_nodes.get(parent_key).unwrap().parent_key;
-                        }
-                        None => break,
-                    }
+    /// Applies `f` to every value inside of this [`Tree`] instance.
+    ///
+    /// Each value is visited exactly once, in an unspecified order.
+    pub fn for_each_value_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut V),
+    {
+        self.inner_nodes
+            .values_mut()
+            .for_each(|inner_node| f(&mut inner_node.value));
+    }
+
+    /// Overwrites the value at the given `key`, as well as the values of *all*
+    /// of its descendents, with clones of `value`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// `false` is returned and no updates to the [`Tree`] are made. Otherwise,
+    /// performs the fill and returns `true`.
+    pub fn fill_subtree(&mut self, key: K, value: V) -> bool
+    where
+        V: Clone,
+    {
+        self.inner_nodes
+            .contains_key(key)
+            .then(|| {
+                let mut to_visit_keys = vec![key];
+
+                while let Some(to_visit_key) = to_visit_keys.pop() {
+                    let inner_node = self.inner_nodes.get_mut(to_visit_key).unwrap();
+                    inner_node.value = value.clone();
+                    to_visit_keys.extend(&inner_node.child_keys);
                 }
+            })
+            .is_some()
+    }
 
-                let mut current_parent_key = tree.inner_nodes.get(key_2).unwrap().parent_key;
+    /// Rolls up the values of this [`Tree`] instance from the bottom up.
+    ///
+    /// Children are always rolled up before their parents (the values are
+    /// visited in reverse level order, *not* in postorder). Each value has the
+    /// (already rolled-up) values of its children folded into it via
+    /// `combine`, which is called once per child (in the order of the
+    /// `child_keys`). After this operation, each value therefore holds the
+    /// aggregate of its entire subtree.
+    pub fn roll_up<F>(&mut self, mut combine: F)
+    where
+        F: FnMut(&mut V, &V),
+    {
+        let mut keys = Vec::with_capacity(self.inner_nodes.len());
+        keys.extend(self.root_key);
 
-                loop {
-                    match current_parent_key {
-                        Some(parent_key) if parent_key == key_1 => {
-                            return Relationship::Ancestral {
-                                ancestor_key: key_1,
-                                descendent_key: key_2,
-                            }
-                        }
-                        Some(parent_key) => {
-                            if path.contains(&parent_key) {
-                                return Relationship::Siblings {
-                                    common_ancestor_key: parent_key,
-                                };
-                            }
-                            else {
-                                current_parent_key =
-                                    tree.inner_nodes.get(parent_key).unwrap().parent_key;
-                            }
-                        }
-                        None => unreachable!(),
+        let mut index = 0;
+
+        while let Some(&key) = keys.get(index) {
+            keys.extend(&self.inner_nodes.get(key).unwrap().child_keys);
+            index += 1;
+        }
+
+        for key in keys.into_iter().rev() {
+            let child_keys_length = self.inner_nodes.get(key).unwrap().child_keys.len();
+
+            for child_index in 0..child_keys_length {
+                let child_key = self.inner_nodes.get(key).unwrap().child_keys[child_index];
+                let [inner_node, child_inner_node] =
+                    self.inner_nodes.get_disjoint_mut([key, child_key]).unwrap();
+                combine(&mut inner_node.value, &child_inner_node.value);
+            }
+        }
+    }
+
+    /// Folds the subtree rooted at the given `key` from the bottom up.
+    ///
+    /// In postorder, each value's accumulator is computed by calling `f` with
+    /// that value and the (already computed) accumulators of its children (in
+    /// the order of the `child_keys`). The accumulator of `key` itself is
+    /// returned.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// accumulator of `key`.
+    pub fn fold<A, F>(&self, key: K, mut f: F) -> Option<A>
+    where
+        F: FnMut(&V, Vec<A>) -> A,
+    {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut to_visit_keys = vec![(key, 0)];
+            let mut accumulators = vec![];
+
+            while let Some((key, next_child_index)) = to_visit_keys.last_mut() {
+                let inner_node = self.inner_nodes.get(*key).unwrap();
+
+                match inner_node.child_keys.get_index(*next_child_index) {
+                    Some(&child_key) => {
+                        *next_child_index += 1;
+                        to_visit_keys.push((child_key, 0));
+                    }
+                    None => {
+                        let child_accumulators = accumulators
+                            .split_off(accumulators.len() - inner_node.child_keys.len());
+                        accumulators.push(f(&inner_node.value, child_accumulators));
+                        to_visit_keys.pop();
                     }
                 }
             }
+
+            accumulators.pop().unwrap()
+        })
+    }
+
+    /// Returns all the keys (and their corresponding values) which share the
+    /// same parent as the given `key`, *including* `key` itself.
+    ///
+    /// The keys are returned in the order of their parent's `child_keys`. If
+    /// the given `key` is the root key, then only the root key (and its value)
+    /// is returned.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// sibling group.
+    pub fn sibling_group_values(&self, key: K) -> Option<Vec<(K, &V)>> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| match inner_node.parent_key {
+                Some(parent_key) => self
+                    .inner_nodes
+                    .get(parent_key)
+                    .unwrap()
+                    .child_keys
+                    .iter()
+                    .map(|&sibling_key| {
+                        (
+                            sibling_key,
+                            &self.inner_nodes.get(sibling_key).unwrap().value,
+                        )
+                    })
+                    .collect(),
+                None => vec![(key, &inner_node.value)],
+            })
+    }
+
+    /// Returns the children keys of the given `key`, sorted by comparing their
+    /// values with `compare`.
+    ///
+    /// The sort is performed on a *copy* of the children keys; the order of the
+    /// `child_keys` stored inside of this [`Tree`] instance is left untouched.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// sorted children keys.
+    pub fn children_sorted_by<F>(&self, key: K, mut compare: F) -> Option<Vec<K>>
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        self.inner_nodes.get(key).map(|inner_node| {
+            let mut child_keys = inner_node.child_keys.iter().copied().collect::<Vec<_>>();
+
+            child_keys.sort_by(|&child_key_1, &child_key_2| {
+                let value_1 = &self.inner_nodes.get(child_key_1).unwrap().value;
+                let value_2 = &self.inner_nodes.get(child_key_2).unwrap().value;
+                compare(value_1, value_2)
+            });
+
+            child_keys
+        })
+    }
+
+    /// Returns all the keys which are descendents of the given `key` (*not*
+    /// including `key` itself).
+    ///
+    /// The order of the returned keys is arbitrary.
+    ///
+    /// The `size_hint` argument allows for one to specify the number of
+    /// descendents the given `key` has. This can be helpful in order
+    /// to allocate only the necessary amount of space and to avoid
+    /// additional allocations + `memcpy`'s.
+    ///
+    /// If you do not have a hint, then provide [`None`] as the argument.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// descendent keys.
+    pub fn descendant_keys(&self, key: K, size_hint: Option<usize>) -> Option<Vec<K>> {
+        self.inner_nodes.get(key).map(|inner_node| {
+            let size_hint = size_hint.unwrap_or_else(|| self.inner_nodes.len());
+
+            let mut descendant_keys = Vec::with_capacity(size_hint);
+            descendant_keys.extend(&inner_node.child_keys);
+
+            let mut index = 0;
+
+            while let Some(&descendant_key) = descendant_keys.get(index) {
+                let child_keys = &self.inner_nodes.get(descendant_key).unwrap().child_keys;
+                descendant_keys.extend(child_keys);
+                index += 1;
+            }
+
+            descendant_keys
+        })
+    }
+
+    /// Returns all the keys which are descendents of the given `key`,
+    /// *including* `key` itself.
+    ///
+    /// The order of the returned keys is arbitrary.
+    ///
+    /// The `size_hint` argument allows for one to specify the number of
+    /// descendents the given `key` has. This can be helpful in order
+    /// to allocate only the necessary amount of space and to avoid
+    /// additional allocations + `memcpy`'s.
+    ///
+    /// If you do not have a hint, then provide [`None`] as the argument.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// descendent keys.
+    pub fn descendant_keys_inclusive(&self, key: K, size_hint: Option<usize>) -> Option<Vec<K>> {
+        self.descendant_keys(key, size_hint.map(|size_hint| size_hint + 1))
+            .map(|mut descendant_keys| {
+                descendant_keys.push(key);
+                descendant_keys
+            })
+    }
+
+    /// Builds a [`SecondaryMap`] which maps every key inside of this [`Tree`]
+    /// instance to the result of calling `f` on that key and its value.
+    ///
+    /// This is useful for storing auxiliary data (e.g., layout information)
+    /// alongside the values, keyed by the same keys.
+    ///
+    /// The order in which `f` is called is arbitrary.
+    pub fn build_secondary<T, F>(&self, mut f: F) -> SecondaryMap<K, T>
+    where
+        F: FnMut(K, &V) -> T,
+    {
+        let mut secondary_map = SecondaryMap::with_capacity(self.inner_nodes.len());
+
+        for (key, inner_node) in &self.inner_nodes {
+            secondary_map.insert(key, f(key, &inner_node.value));
+        }
+
+        secondary_map
+    }
+
+    /// Returns the index of every key inside of its parent's `child_keys`.
+    ///
+    /// The root key has no parent and is therefore omitted from the returned
+    /// [`SecondaryMap`], which gives constant-time lookup by tree key.
+    pub fn child_positions(&self) -> SecondaryMap<K, usize> {
+        let mut child_positions = SecondaryMap::with_capacity(self.inner_nodes.len());
+
+        for inner_node in self.inner_nodes.values() {
+            for (index, &child_key) in inner_node.child_keys.iter().enumerate() {
+                child_positions.insert(child_key, index);
+            }
         }
 
-        let key_1_exists = self.inner_nodes.contains_key(key_1);
-        let key_2_exists = self.inner_nodes.contains_key(key_2);
-        let both_keys_exist = key_1_exists && key_2_exists;
+        child_positions
+    }
+
+    /// Returns the depth of every key inside of this [`Tree`] instance,
+    /// computed in a single breadth-first pass from the root (which has a
+    /// depth of `0`).
+    ///
+    /// An empty [`Tree`] instance produces an empty map.
+    pub fn depth_map(&self) -> SecondaryMap<K, usize> {
+        self.root_key
+            .and_then(|root_key| self.relative_depth_map(root_key))
+            .unwrap_or_default()
+    }
+
+    /// Returns the depth of every key in the subtree rooted at the given `key`,
+    /// relative to `key` (i.e., `key` itself has a depth of `0`, its children
+    /// have a depth of `1`, etc.).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// relative depths.
+    pub fn relative_depth_map(&self, key: K) -> Option<SecondaryMap<K, usize>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut depth_map = SecondaryMap::new();
+            let mut to_visit_keys = VecDeque::from([(key, 0)]);
+
+            while let Some((to_visit_key, depth)) = to_visit_keys.pop_front() {
+                depth_map.insert(to_visit_key, depth);
+
+                let child_keys = &self.inner_nodes.get(to_visit_key).unwrap().child_keys;
+                to_visit_keys.extend(child_keys.iter().map(|&child_key| (child_key, depth + 1)));
+            }
+
+            depth_map
+        })
+    }
+
+    /// Returns the keys of this [`Tree`] instance grouped by their depth.
+    ///
+    /// The outer index is the depth (i.e., index `0` contains just the root
+    /// key), and each inner [`Vec`] contains the keys at that depth in
+    /// breadth-first order (i.e., from left to right).
+    ///
+    /// Returns an empty [`Vec`] if this [`Tree`] instance is empty.
+    pub fn levels(&self) -> Vec<Vec<K>> {
+        let mut levels = vec![];
+        let mut level_keys = Vec::from_iter(self.root_key);
+
+        while !level_keys.is_empty() {
+            let mut next_level_keys = vec![];
+
+            for &key in &level_keys {
+                next_level_keys.extend(&self.inner_nodes.get(key).unwrap().child_keys);
+            }
+
+            levels.push(level_keys);
+            level_keys = next_level_keys;
+        }
+
+        levels
+    }
+
+    /// Returns the values of this [`Tree`] instance grouped by their depth.
+    ///
+    /// The outer index is the depth (i.e., index `0` contains just the root
+    /// value), and each inner [`Vec`] contains the values at that depth from
+    /// left to right.
+    ///
+    /// Returns an empty [`Vec`] if this [`Tree`] instance is empty.
+    pub fn level_values(&self) -> Vec<Vec<&V>> {
+        self.levels()
+            .into_iter()
+            .map(|level_keys| {
+                level_keys
+                    .into_iter()
+                    .map(|key| &self.inner_nodes.get(key).unwrap().value)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Counts the number of nodes inside of this [`Tree`] instance whose values
+    /// satisfy the given `predicate`.
+    pub fn count_where<F>(&self, mut predicate: F) -> usize
+    where
+        F: FnMut(&V) -> bool,
+    {
+        self.inner_nodes
+            .values()
+            .filter(|inner_node| predicate(&inner_node.value))
+            .count()
+    }
+
+    /// Counts the number of nodes at the given (absolute) `depth` whose
+    /// values satisfy the given `predicate`.
+    ///
+    /// The root is at depth `0`. Nodes below `depth` are never visited.
+    pub fn count_matching_at_depth<F>(&self, depth: usize, mut predicate: F) -> usize
+    where
+        F: FnMut(&V) -> bool,
+    {
+        let mut level_keys = Vec::from_iter(self.root_key);
+
+        for _ in 0..depth {
+            let mut next_level_keys = vec![];
+
+            for key in level_keys {
+                let inner_node = self.inner_nodes.get(key).unwrap();
+                next_level_keys.extend(&inner_node.child_keys);
+            }
+
+            level_keys = next_level_keys;
+        }
+
+        level_keys
+            .into_iter()
+            .filter(|&key| predicate(&self.inner_nodes.get(key).unwrap().value))
+            .count()
+    }
+
+    /// Returns the number of direct children of the given `key`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// number of children.
+    pub fn child_count(&self, key: K) -> Option<usize> {
+        self.inner_nodes
+            .get(key)
+            .map(|inner_node| inner_node.child_keys.len())
+    }
+
+    /// Returns the number of values in the subtree rooted at the given `key`
+    /// (i.e., `key` itself plus *all* of its descendents).
+    ///
+    /// A leaf has a subtree size of `1`. The result minus one is the exact
+    /// `size_hint` that [`Tree::remove`] accepts.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// subtree size.
+    pub fn subtree_size(&self, key: K) -> Option<usize> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut subtree_size = 0;
+            let mut to_visit_keys = vec![key];
+
+            while let Some(to_visit_key) = to_visit_keys.pop() {
+                subtree_size += 1;
+                to_visit_keys.extend(&self.inner_nodes.get(to_visit_key).unwrap().child_keys);
+            }
+
+            subtree_size
+        })
+    }
+
+    /// Returns the height of the given `key` (i.e., the number of edges on the
+    /// longest path from `key` down to one of its descendent leaves).
+    ///
+    /// A leaf has a height of `0`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// height.
+    pub fn height(&self, key: K) -> Option<usize> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut height = 0;
+            let mut to_visit_keys = vec![(key, 0)];
+
+            while let Some((to_visit_key, depth)) = to_visit_keys.pop() {
+                height = height.max(depth);
+
+                let child_keys = &self.inner_nodes.get(to_visit_key).unwrap().child_keys;
+                to_visit_keys.extend(child_keys.iter().map(|&child_key| (child_key, depth + 1)));
+            }
+
+            height
+        })
+    }
+
+    /// Returns the keys along the longest path between any two keys inside of
+    /// this [`Tree`] instance (i.e., the diameter of this [`Tree`] instance).
+    ///
+    /// The path starts at one endpoint and ends at the other. If there are
+    /// multiple longest paths, then one of them is chosen arbitrarily.
+    ///
+    /// Returns an empty [`Vec`] if this [`Tree`] instance is empty.
+    pub fn diameter_path(&self) -> Vec<K> {
+        let Some(root_key) = self.root_key
+        else {
+            return vec![];
+        };
+
+        // # Note:
+        // Maps each key to its height and the child key through which its deepest
+        // descendent can be reached.
+        let mut heights = SecondaryMap::<K, (usize, Option<K>)>::with_capacity(self.len());
+        let mut diameter = (0, root_key, None, None);
+
+        for (key, node) in self.postorder(root_key).unwrap() {
+            let mut deepest = None::<(usize, K)>;
+            let mut second_deepest = None::<(usize, K)>;
+
+            for &child_key in node.child_keys {
+                let height = heights.get(child_key).unwrap().0 + 1;
+
+                if !matches!(deepest, Some((deepest_height, _)) if deepest_height >= height) {
+                    second_deepest = deepest;
+                    deepest = Some((height, child_key));
+                }
+                else if !matches!(second_deepest, Some((second_height, _)) if second_height >= height)
+                {
+                    second_deepest = Some((height, child_key));
+                };
+            }
+
+            let height = deepest.map_or(0, |(height, _)| height);
+            let length = height + second_deepest.map_or(0, |(height, _)| height);
+
+            if length > diameter.0 {
+                diameter = (
+                    length,
+                    key,
+                    deepest.map(|(_, child_key)| child_key),
+                    second_deepest.map(|(_, child_key)| child_key),
+                );
+            };
+
+            heights.insert(key, (height, deepest.map(|(_, child_key)| child_key)));
+        }
+
+        let descend = |mut current_key: Option<K>, path: &mut Vec<K>| {
+            while let Some(key) = current_key {
+                path.push(key);
+                current_key = heights.get(key).unwrap().1;
+            }
+        };
+
+        let (length, apex_key, deepest_key, second_deepest_key) = diameter;
+        let mut path = Vec::with_capacity(length + 1);
+
+        descend(deepest_key, &mut path);
+        path.reverse();
+        path.push(apex_key);
+        descend(second_deepest_key, &mut path);
+
+        path
+    }
+
+    /// Returns the number of distinct paths from the root of this [`Tree`]
+    /// instance down to one of its leaves.
+    ///
+    /// Returns `0` if this [`Tree`] instance is empty.
+    pub fn leaf_path_count(&self) -> usize {
+        self.root_key
+            .and_then(|root_key| self.leaf_path_count_under(root_key))
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of distinct paths from the given `key` down to one of
+    /// its descendent leaves.
+    ///
+    /// If the given `key` is a leaf itself, then there is exactly one such path
+    /// (i.e., the path containing just `key`).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// number of paths.
+    pub fn leaf_path_count_under(&self, key: K) -> Option<usize> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut leaf_path_count = 0;
+            let mut to_visit_keys = vec![key];
+
+            while let Some(to_visit_key) = to_visit_keys.pop() {
+                let child_keys = &self.inner_nodes.get(to_visit_key).unwrap().child_keys;
+
+                if child_keys.is_empty() {
+                    leaf_path_count += 1;
+                }
+                else {
+                    to_visit_keys.extend(child_keys);
+                };
+            }
+
+            leaf_path_count
+        })
+    }
+
+    /// Gets the [`Relationship`] status between two keys.
+    ///
+    /// If either `key_1` or `key_2` do not exist in this [`Tree`] instance,
+    /// then [`None`] is returned. Otherwise, returns [`Some(..)`]
+    /// containing the relationship between the two keys.
+    pub fn get_relationship(&self, key_1: K, key_2: K) -> Option<Relationship<K>> {
+        fn get_relationship<K, V>(tree: &Tree<K, V>, key_1: K, key_2: K) -> Relationship<K>
+        where
+            K: Key,
+        {
+            if key_1 == key_2 {
+                Relationship::Same
+            }
+            else {
+                let mut current_parent_key = tree.inner_nodes.get(key_1).unwrap().parent_key;
+                let length = tree.inner_nodes.len();
+                let mut path = index_set_with_capacity(length);
+
+                loop {
+                    match current_parent_key {
+                        Some(parent_key) if parent_key == key_2 => {
+                            return Relationship::Ancestral {
+                                ancestor_key: key_2,
+                                descendent_key: key_1,
+                            }
+                        }
+                        Some(parent_key) => {
+                            path.insert(parent_key);
+                            current_parent_key =
+                                tree.inner_nodes.get(parent_key).unwrap().parent_key;
+                        }
+                        None => break,
+                    }
+                }
+
+                let mut current_parent_key = tree.inner_nodes.get(key_2).unwrap().parent_key;
+
+                loop {
+                    match current_parent_key {
+                        Some(parent_key) if parent_key == key_1 => {
+                            return Relationship::Ancestral {
+                                ancestor_key: key_1,
+                                descendent_key: key_2,
+                            }
+                        }
+                        Some(parent_key) => {
+                            if path.contains(&parent_key) {
+                                return Relationship::Siblings {
+                                    common_ancestor_key: parent_key,
+                                };
+                            }
+                            else {
+                                current_parent_key =
+                                    tree.inner_nodes.get(parent_key).unwrap().parent_key;
+                            }
+                        }
+                        None => unreachable!(),
+                    }
+                }
+            }
+        }
+
+        let key_1_exists = self.inner_nodes.contains_key(key_1);
+        let key_2_exists = self.inner_nodes.contains_key(key_2);
+        let both_keys_exist = key_1_exists && key_2_exists;
+
+        both_keys_exist.then(|| get_relationship(self, key_1, key_2))
+    }
+
+    /// Gets the lowest common ancestor of `key_1` and `key_2` (i.e., the key at
+    /// which the parental lineages of both keys converge).
+    ///
+    /// If one key is an ancestor of the other (or both keys are the same),
+    /// then that key *is* the common ancestor.
+    ///
+    /// If either `key_1` or `key_2` do not exist in this [`Tree`] instance,
+    /// then [`None`] is returned. Otherwise, returns [`Some(..)`]
+    /// containing the common ancestor key.
+    pub fn common_ancestor(&self, key_1: K, key_2: K) -> Option<K> {
+        self.get_relationship(key_1, key_2)
+            .map(|relationship| match relationship {
+                Relationship::Same => key_1,
+                Relationship::Ancestral { ancestor_key, .. } => ancestor_key,
+                Relationship::Siblings {
+                    common_ancestor_key,
+                } => common_ancestor_key,
+            })
+    }
+
+    /// Gets the two paths which connect `key_1` and `key_2` through their
+    /// lowest common ancestor.
+    ///
+    /// The first path starts at `key_1` and walks up its parental lineage up
+    /// to (and including) the common ancestor; the second path does the same
+    /// for `key_2`. If one key is an ancestor of the other, then that key *is*
+    /// the common ancestor, and its path only contains itself.
+    ///
+    /// If either `key_1` or `key_2` do not exist in this [`Tree`] instance,
+    /// then [`None`] is returned. Otherwise, returns [`Some(..)`]
+    /// containing the two paths.
+    pub fn path_between(&self, key_1: K, key_2: K) -> Option<(Vec<K>, Vec<K>)> {
+        fn path_up_to<K, V>(tree: &Tree<K, V>, key: K, ancestor_key: K) -> Vec<K>
+        where
+            K: Key,
+        {
+            let mut path = vec![key];
+            let mut current_key = key;
+
+            while current_key != ancestor_key {
+                current_key = tree
+                    .inner_nodes
+                    .get(current_key)
+                    .unwrap()
+                    .parent_key
+                    .unwrap();
+                path.push(current_key);
+            }
+
+            path
+        }
+
+        self.common_ancestor(key_1, key_2)
+            .map(|common_ancestor_key| {
+                (
+                    path_up_to(self, key_1, common_ancestor_key),
+                    path_up_to(self, key_2, common_ancestor_key),
+                )
+            })
+    }
+
+    /// Gets the number of edges between `key_1` and `key_2`.
+    ///
+    /// If one key is an ancestor of the other, then this is the number of hops
+    /// between them (and `0` if both keys are the same). Otherwise, this is the
+    /// sum of both keys' distances to their lowest common ancestor.
+    ///
+    /// If either `key_1` or `key_2` do not exist in this [`Tree`] instance,
+    /// then [`None`] is returned. Otherwise, returns [`Some(..)`]
+    /// containing the distance.
+    pub fn distance(&self, key_1: K, key_2: K) -> Option<usize> {
+        self.path_between(key_1, key_2)
+            .map(|(path_1, path_2)| path_1.len() + path_2.len() - 2)
+    }
+
+    /// Gets the lineage of the given `key` (i.e., the keys starting at `key`
+    /// and walking up its parental lineage up to (and including) the root key).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// path.
+    pub fn path_to_root(&self, key: K) -> Option<Vec<K>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut path = vec![key];
+            let mut current_parent_key = self.inner_nodes.get(key).unwrap().parent_key;
+
+            while let Some(parent_key) = current_parent_key {
+                path.push(parent_key);
+                current_parent_key = self.inner_nodes.get(parent_key).unwrap().parent_key;
+            }
+
+            path
+        })
+    }
+
+    /// Renders this [`Tree`] instance as a human-readable string, with one
+    /// value per line and `|--` connectors (and `` `-- `` for the last child of
+    /// each value) drawing the branches:
+    ///
+    /// ```md
+    /// 0
+    /// |-- 1
+    /// |   |-- 3
+    /// |   `-- 4
+    /// `-- 2
+    /// ```
+    ///
+    /// Children are rendered in order. An empty [`Tree`] instance is rendered
+    /// as an empty string.
+    pub fn to_pretty_string(&self) -> String
+    where
+        V: fmt::Display,
+    {
+        let mut lines = Vec::with_capacity(self.inner_nodes.len());
+        let mut to_visit_keys = Vec::from_iter(self.root_key.map(|root_key| (root_key, None)));
+
+        while let Some((key, branch)) = to_visit_keys.pop() {
+            let inner_node = self.inner_nodes.get(key).unwrap();
+
+            let child_prefix = match branch {
+                Some((prefix, is_last)) => {
+                    let (connector, continuation) = if is_last {
+                        ("`-- ", "    ")
+                    }
+                    else {
+                        ("|-- ", "|   ")
+                    };
+                    lines.push(format!("{prefix}{connector}{}", inner_node.value));
+                    format!("{prefix}{continuation}")
+                }
+                None => {
+                    lines.push(inner_node.value.to_string());
+                    String::new()
+                }
+            };
+
+            let last_index = inner_node.child_keys.len().saturating_sub(1);
+            to_visit_keys.extend(inner_node.child_keys.iter().enumerate().rev().map(
+                |(index, &child_key)| {
+                    (child_key, Some((child_prefix.clone(), index == last_index)))
+                },
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Renders this [`Tree`] instance as a [Graphviz](https://graphviz.org)
+    /// `digraph` in the DOT language.
+    ///
+    /// Each value is emitted as a node labelled with that value, and each
+    /// parent/child link is emitted as a directed edge from the parent to the
+    /// child. Nodes are identified by their position in a depth-first
+    /// (pre-order) traversal, and are emitted in that same order (with
+    /// children in order), so the output is deterministic.
+    pub fn to_dot(&self) -> String
+    where
+        V: fmt::Display,
+    {
+        let mut nodes = String::new();
+        let mut edges = String::new();
+        let mut to_visit_keys = Vec::from_iter(self.root_key.map(|root_key| (root_key, None)));
+        let mut index = 0;
+
+        while let Some((key, parent_index)) = to_visit_keys.pop() {
+            let inner_node = self.inner_nodes.get(key).unwrap();
+            let label = inner_node
+                .value
+                .to_string()
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"");
+
+            nodes.push_str(&format!("    n{index} [label=\"{label}\"];\n"));
+
+            if let Some(parent_index) = parent_index {
+                edges.push_str(&format!("    n{parent_index} -> n{index};\n"));
+            };
+
+            to_visit_keys.extend(
+                inner_node
+                    .child_keys
+                    .iter()
+                    .rev()
+                    .map(|&child_key| (child_key, Some(index))),
+            );
+            index += 1;
+        }
+
+        format!("digraph {{\n{nodes}{edges}}}\n")
+    }
+
+    // Iter methods:
+
+    /// Returns an owned iterator over all the keys inside of this [`Tree`]
+    /// instance.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            inner: self.inner_nodes.keys(),
+        }
+    }
+
+    /// Returns an immutable iterator over all the [`Node`]s inside of this
+    /// [`Tree`] instance.
+    pub fn nodes(&self) -> Nodes<'_, K, V> {
+        Nodes {
+            inner: self.inner_nodes.values(),
+        }
+    }
+
+    /// Returns a mutable iterator over all the [`Node`]s inside of this
+    /// [`Tree`] instance.
+    pub fn nodes_mut(&mut self) -> NodesMut<'_, K, V> {
+        NodesMut {
+            inner: self.inner_nodes.values_mut(),
+        }
+    }
+
+    /// Returns an immutable iterator over all the values inside of this
+    /// [`Tree`] instance.
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            inner: self.inner_nodes.values(),
+        }
+    }
+
+    /// Returns a mutable iterator over all the values inside of this [`Tree`]
+    /// instance.
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.inner_nodes.values_mut(),
+        }
+    }
+
+    /// Create an immutable iterator over the key-value pairs inside of this
+    /// [`Tree`] instance.
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.inner_nodes.iter(),
+        }
+    }
+
+    /// Create a mutable iterator over the key-value pairs inside of this
+    /// [`Tree`] instance.
+    ///
+    /// Note that this iterator will yield elements of type `(K, &mut V)`.
+    /// Namely, this function only provides mutable access to the values, not
+    /// the keys!
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.inner_nodes.iter_mut(),
+        }
+    }
+
+    /// Create an iterator which moves all the key-value pairs out of this
+    /// [`Tree`] instance, leaving it empty.
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    ///
+    /// Once the iterator has been dropped, this [`Tree`] instance is empty
+    /// (even if the iterator was not exhausted, in which case the remaining
+    /// values are dropped).
+    pub fn drain(&mut self) -> impl '_ + Iterator<Item = (K, V)> {
+        self.root_key = None;
+        self.inner_nodes
+            .drain()
+            .map(|(key, inner_node)| (key, inner_node.value))
+    }
+
+    /// Create an immutable iterator over the key-value pairs of all the leaves
+    /// (i.e., the values without any children) inside of this [`Tree`]
+    /// instance.
+    ///
+    /// The order of iteration is arbitrary. It will not be guaranteed to be
+    /// depth-first, breadth-first, in-order, etc.
+    pub fn leaves(&self) -> impl Iterator<Item = (K, Node<'_, K, V>)> {
+        self.iter().filter(|(_, node)| node.child_keys.is_empty())
+    }
+
+    /// Create an immutable, depth-first iterator over the key-value pairs of
+    /// the subtree rooted at the given `key`.
+    ///
+    /// The iteration is performed in preorder. Namely, `key` is yielded first,
+    /// followed by the entire subtree of each of its children (in the order of
+    /// the `child_keys`).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn preorder(&self, key: K) -> Option<impl Iterator<Item = (K, Node<'_, K, V>)>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut to_visit_keys = vec![key];
+
+            from_fn(move || {
+                to_visit_keys.pop().map(|key| {
+                    let inner_node = self.inner_nodes.get(key).unwrap();
+                    to_visit_keys.extend(inner_node.child_keys.iter().rev());
+
+                    (
+                        key,
+                        Node {
+                            parent_key: inner_node.parent_key,
+                            child_keys: &inner_node.child_keys,
+                            value: &inner_node.value,
+                        },
+                    )
+                })
+            })
+        })
+    }
+
+    /// Create a depth-first iterator over the keys of the subtree rooted at the
+    /// given `key`.
+    ///
+    /// The iteration is performed in preorder, exactly like
+    /// [`Tree::preorder`], but only the keys are yielded (i.e., no [`Node`]s
+    /// are constructed).
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn preorder_keys(&self, key: K) -> Option<impl '_ + Iterator<Item = K>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut to_visit_keys = vec![key];
+
+            from_fn(move || {
+                to_visit_keys.pop().map(|key| {
+                    let inner_node = self.inner_nodes.get(key).unwrap();
+                    to_visit_keys.extend(inner_node.child_keys.iter().rev());
+                    key
+                })
+            })
+        })
+    }
+
+    /// Create a mutable, depth-first iterator over the key-value pairs of the
+    /// subtree rooted at the given `key`.
+    ///
+    /// The iteration is performed in preorder, exactly like
+    /// [`Tree::preorder`].
+    ///
+    /// # Note:
+    /// Since the yielded [`NodeMut`]s must not alias each other, the position
+    /// of each of the subtree's keys is collected up front, and a single pass
+    /// over all of the values then hands out a [`NodeMut`] for each of those
+    /// keys (exactly once). Only the subtree's [`NodeMut`]s are kept around,
+    /// but the iterator is *not* lazy: creating it visits every value in this
+    /// [`Tree`] instance, taking time proportional to the size of the whole
+    /// [`Tree`] even if the subtree rooted at `key` is small.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn preorder_mut(&mut self, key: K) -> Option<impl Iterator<Item = (K, NodeMut<'_, K, V>)>> {
+        self.preorder_keys(key)
+            .map(|iter| iter.collect::<KeySet<_>>())
+            .map(|positions| {
+                let mut nodes = Vec::with_capacity(positions.len());
+                nodes.resize_with(positions.len(), || None);
+
+                for (key, node) in self.iter_mut() {
+                    if let Some(position) = positions.get_index_of(&key) {
+                        nodes[position] = Some((key, node));
+                    };
+                }
+
+                nodes.into_iter().map(Option::unwrap)
+            })
+    }
+
+    /// Create a lazy iterator over the key-value pairs of the subtree rooted at
+    /// the given `key`.
+    ///
+    /// This is an alias of [`Tree::preorder`]. Unlike
+    /// [`Tree::descendant_keys`], the keys of the subtree are *not* collected
+    /// up front; each node is only visited once the iterator is advanced onto
+    /// it.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn subtree(&self, key: K) -> Option<impl Iterator<Item = (K, Node<'_, K, V>)>> {
+        self.preorder(key)
+    }
+
+    /// Visits the subtree rooted at the given `key` in depth-first (pre-order)
+    /// order, calling `f` with each key, its value, and its depth relative to
+    /// `key` (i.e., `key` itself has a depth of `0`).
+    ///
+    /// Children are visited in order. If the given `key` does not exist in this
+    /// [`Tree`] instance, then this is a no-op.
+    pub fn visit_preorder<F>(&self, key: K, mut f: F)
+    where
+        F: FnMut(K, &V, usize),
+    {
+        if self.inner_nodes.contains_key(key) {
+            let mut to_visit_keys = vec![(key, 0)];
+
+            while let Some((key, depth)) = to_visit_keys.pop() {
+                let inner_node = self.inner_nodes.get(key).unwrap();
+                f(key, &inner_node.value, depth);
+
+                to_visit_keys.extend(
+                    inner_node
+                        .child_keys
+                        .iter()
+                        .rev()
+                        .map(|&child_key| (child_key, depth + 1)),
+                );
+            }
+        };
+    }
+
+    /// Create an immutable, depth-first iterator over the key-value pairs of
+    /// the subtree rooted at the given `key`.
+    ///
+    /// The iteration is performed in postorder. Namely, the entire subtree of
+    /// each child of `key` (in the order of the `child_keys`) is yielded
+    /// first, followed by `key` itself. Every key is therefore guaranteed to
+    /// be yielded *after* all of its descendents.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn postorder(&self, key: K) -> Option<impl Iterator<Item = (K, Node<'_, K, V>)>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut to_visit_keys = vec![(key, 0)];
+
+            from_fn(move || loop {
+                let (key, next_child_index) = to_visit_keys.last_mut()?;
+                let inner_node = self.inner_nodes.get(*key).unwrap();
+
+                match inner_node.child_keys.get_index(*next_child_index) {
+                    Some(&child_key) => {
+                        *next_child_index += 1;
+                        to_visit_keys.push((child_key, 0));
+                    }
+                    None => {
+                        let (key, _) = to_visit_keys.pop().unwrap();
+
+                        break Some((
+                            key,
+                            Node {
+                                parent_key: inner_node.parent_key,
+                                child_keys: &inner_node.child_keys,
+                                value: &inner_node.value,
+                            },
+                        ));
+                    }
+                }
+            })
+        })
+    }
+
+    /// Create an immutable, breadth-first iterator over the key-value pairs of
+    /// the subtree rooted at the given `key`.
+    ///
+    /// The iteration is performed in level-order. Namely, `key` is yielded
+    /// first, followed by all of its children, followed by all of its
+    /// grandchildren, etc. The keys within each level are yielded in the
+    /// order of their parents' `child_keys`.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn bfs(&self, key: K) -> Option<impl Iterator<Item = (K, Node<'_, K, V>)>> {
+        self.inner_nodes.contains_key(key).then(|| {
+            let mut to_visit_keys = VecDeque::from([key]);
+
+            from_fn(move || {
+                to_visit_keys.pop_front().map(|key| {
+                    let inner_node = self.inner_nodes.get(key).unwrap();
+                    to_visit_keys.extend(&inner_node.child_keys);
+
+                    (
+                        key,
+                        Node {
+                            parent_key: inner_node.parent_key,
+                            child_keys: &inner_node.child_keys,
+                            value: &inner_node.value,
+                        },
+                    )
+                })
+            })
+        })
+    }
+
+    /// Create an iterator over all the keys inside of this [`Tree`] instance,
+    /// each paired with its `parent_key`.
+    ///
+    /// The iteration is performed in breadth-first order, starting from the
+    /// root (which is paired with [`None`]). Therefore, every key is
+    /// guaranteed to be yielded *after* its parent.
+    pub fn bfs_with_parent(&self) -> impl '_ + Iterator<Item = (K, Option<K>)> {
+        let mut to_visit_keys = VecDeque::with_capacity(self.inner_nodes.len());
+        to_visit_keys.extend(self.root_key);
+
+        from_fn(move || {
+            to_visit_keys.pop_front().map(|key| {
+                let inner_node = self.inner_nodes.get(key).unwrap();
+                to_visit_keys.extend(&inner_node.child_keys);
+                (key, inner_node.parent_key)
+            })
+        })
+    }
+
+    /// Create an iterator over the siblings of the given `key` (i.e., the
+    /// other children of its parent), in order.
+    ///
+    /// The given `key` itself is never yielded. The root key has no siblings,
+    /// so it yields an empty iterator.
+    ///
+    /// If the given `key` does not exist in this [`Tree`] instance, then
+    /// [`None`] is returned. Otherwise, returns [`Some(..)`] containing the
+    /// iterator.
+    pub fn siblings(&self, key: K) -> Option<impl '_ + Iterator<Item = K>> {
+        self.inner_nodes.get(key).map(|inner_node| {
+            inner_node
+                .parent_key
+                .into_iter()
+                .flat_map(|parent_key| &self.inner_nodes.get(parent_key).unwrap().child_keys)
+                .copied()
+                .filter(move |&sibling_key| sibling_key != key)
+        })
+    }
+
+    /// Calls `f` with the `(parent_key, child_key)` pair of every edge inside
+    /// of this [`Tree`] instance.
+    ///
+    /// The edges are visited in preorder (i.e., the order in which their child
+    /// keys would be yielded by [`Tree::preorder`] from the root).
+    pub fn for_each_edge<F>(&self, mut f: F)
+    where
+        F: FnMut(K, K),
+    {
+        if let Some(root_key) = self.root_key {
+            for (key, node) in self.preorder(root_key).unwrap() {
+                if let Some(parent_key) = node.parent_key {
+                    f(parent_key, key);
+                };
+            }
+        };
+    }
+
+    /// Create an iterator over the `(parent_key, child_key)` pair of every
+    /// edge inside of this [`Tree`] instance.
+    ///
+    /// The edges are yielded in breadth-first order (i.e., the order in which
+    /// their child keys would be yielded by [`Tree::bfs_with_parent`]), so the
+    /// edges of each parent are grouped together in the order of its
+    /// `child_keys`. An empty [`Tree`] instance (or one containing only a
+    /// root) yields no edges.
+    pub fn edges(&self) -> impl '_ + Iterator<Item = (K, K)> {
+        self.bfs_with_parent()
+            .filter_map(|(key, parent_key)| parent_key.map(|parent_key| (parent_key, key)))
+    }
+
+    // Helper methods:
+
+    /// Moves the nodes identified by `child_keys` (and all of their
+    /// descendents) out of `inner_nodes` and into this [`Tree`] instance,
+    /// underneath the given `new_parent_key` (including the order of all
+    /// children).
+    ///
+    /// The nodes are moved in breadth-first order and are given fresh keys.
+    fn move_descendants(
+        &mut self,
+        inner_nodes: &mut SlotMap<K, InnerNode<K, V>>,
+        child_keys: KeySet<K>,
+        new_parent_key: K,
+    ) {
+        let mut to_visit_keys = VecDeque::from([(child_keys, new_parent_key)]);
+
+        while let Some((child_keys, new_parent_key)) = to_visit_keys.pop_front() {
+            for child_key in child_keys {
+                let child_inner_node = inner_nodes.remove(child_key).unwrap();
+                let new_child_key = self
+                    .insert_with_capacity(
+                        child_inner_node.value,
+                        new_parent_key,
+                        child_inner_node.child_keys.len(),
+                    )
+                    .unwrap();
+
+                to_visit_keys.push_back((child_inner_node.child_keys, new_child_key));
+            }
+        }
+    }
+
+    /// Removes the node corresponding to the given `key` (and all of its
+    /// descendents) from this [`Tree`] instance, unlinking it from its parent.
+    ///
+    /// Returns [`None`] if `key` does not exist. Otherwise, returns the removed
+    /// [`InnerNode`] of `key` itself; the descendents are dropped.
+    fn remove_subtree(&mut self, key: K, size_hint: Option<usize>) -> Option<InnerNode<K, V>> {
+        let root_key = self.root_key?;
+
+        if key == root_key {
+            let inner_node = self.inner_nodes.remove(root_key).unwrap();
+            self.clear();
+            return Some(inner_node);
+        };
+
+        let inner_node = self.inner_nodes.remove(key)?;
+        let size_hint = size_hint.unwrap_or_else(|| self.inner_nodes.len());
+
+        let mut to_visit_keys = Vec::with_capacity(size_hint);
+        to_visit_keys.extend(inner_node.child_keys.iter().copied());
+
+        while let Some(to_visit_key) = to_visit_keys.pop() {
+            let inner_node = self.inner_nodes.remove(to_visit_key).unwrap();
+            to_visit_keys.extend(inner_node.child_keys);
+        }
+
+        let parent_key = inner_node.parent_key.unwrap();
+        self.inner_nodes
+            .get_mut(parent_key)
+            .unwrap()
+            .child_keys
+            .shift_remove(&key);
+
+        Some(inner_node)
+    }
+
+    /// Inserts a new value as a child of `sibling_key`'s parent, positioned at
+    /// `sibling_key`'s index plus the given `offset` (i.e., `0` inserts the
+    /// value before `sibling_key`, and `1` inserts it after).
+    ///
+    /// Returns [`None`] if `sibling_key` does not exist or is the root key.
+    fn insert_next_to(&mut self, value: V, sibling_key: K, offset: usize) -> Option<K> {
+        self.parent_key(sibling_key).flatten().map(|parent_key| {
+            let key = self.insert(value, parent_key).unwrap();
+
+            let child_keys = &mut self.inner_nodes.get_mut(parent_key).unwrap().child_keys;
+            let sibling_index = child_keys.get_index_of(&sibling_key).unwrap();
+            child_keys.move_index(child_keys.len() - 1, sibling_index + offset);
+
+            key
+        })
+    }
+
+    /// Creates a new [`Tree`] instance with the exact same structure as this
+    /// [`Tree`] instance (including the order of all children), in which each
+    /// value is produced by calling `f` on the corresponding key and value of
+    /// this [`Tree`] instance.
+    ///
+    /// The values are visited in breadth-first order. If `f` returns an error,
+    /// then the construction is aborted and that error is returned.
+    fn map_structure<U, E, F>(&self, f: F) -> Result<Tree<K, U>, E>
+    where
+        F: FnMut(K, &V) -> Result<U, E>,
+    {
+        match self.root_key {
+            Some(root_key) => self.map_subtree_structure(root_key, self.inner_nodes.len(), f),
+            None => Ok(Tree::default()),
+        }
+    }
+
+    /// Creates a new [`Tree`] instance with the exact same structure as the
+    /// subtree rooted at the given `key` (including the order of all children),
+    /// in which each value is produced by calling `f` on the corresponding key
+    /// and value of this [`Tree`] instance.
+    ///
+    /// The values are visited in breadth-first order. If `f` returns an error,
+    /// then the construction is aborted and that error is returned.
+    ///
+    /// The given `key` must exist in this [`Tree`] instance.
+    fn map_subtree_structure<U, E, F>(
+        &self,
+        key: K,
+        capacity: usize,
+        mut f: F,
+    ) -> Result<Tree<K, U>, E>
+    where
+        F: FnMut(K, &V) -> Result<U, E>,
+    {
+        let mut tree = Tree::with_capacity(capacity);
+
+        let inner_node = self.inner_nodes.get(key).unwrap();
+        let new_root_key =
+            tree.insert_root_with_capacity(f(key, &inner_node.value)?, inner_node.child_keys.len());
+
+        let mut to_visit_keys = VecDeque::with_capacity(capacity);
+        to_visit_keys.push_back((key, new_root_key));
+
+        while let Some((key, new_key)) = to_visit_keys.pop_front() {
+            for &child_key in &self.inner_nodes.get(key).unwrap().child_keys {
+                let child_inner_node = self.inner_nodes.get(child_key).unwrap();
+                let new_child_key = tree
+                    .insert_with_capacity(
+                        f(child_key, &child_inner_node.value)?,
+                        new_key,
+                        child_inner_node.child_keys.len(),
+                    )
+                    .unwrap();
+
+                to_visit_keys.push_back((child_key, new_child_key));
+            }
+        }
+
+        Ok(tree)
+    }
+}
+
+impl<K, V> Default for Tree<K, V>
+where
+    K: Key,
+{
+    fn default() -> Self {
+        Self {
+            root_key: None,
+            inner_nodes: SlotMap::default(),
+        }
+    }
+}
+
+/// Renders this [`Tree`] instance as an indented, nested list of `key: value`
+/// lines, starting from the root and descending through each value's children
+/// in order.
+///
+/// An empty [`Tree`] instance is rendered as `Tree(empty)`.
+impl<K, V> fmt::Debug for Tree<K, V>
+where
+    K: Key,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.root_key {
+            Some(root_key) => {
+                write!(f, "Tree")?;
+
+                let mut to_visit_keys = vec![(root_key, 1)];
+
+                while let Some((key, depth)) = to_visit_keys.pop() {
+                    let inner_node = self.inner_nodes.get(key).unwrap();
+                    let indent = depth * 4;
+                    write!(f, "\n{:indent$}{key:?}: {:?}", "", inner_node.value)?;
+
+                    to_visit_keys.extend(
+                        inner_node
+                            .child_keys
+                            .iter()
+                            .rev()
+                            .map(|&child_key| (child_key, depth + 1)),
+                    );
+                }
+
+                Ok(())
+            }
+            None => write!(f, "Tree(empty)"),
+        }
+    }
+}
+
+/// Compares two [`Tree`] instances *exactly*, including their keys.
+///
+/// Two [`Tree`]s are equal iff they have the same root key, the same set of
+/// keys, and each key has the same parent key, the same children keys (in the
+/// same order) and an equal value. This is mostly useful for [`Tree`]s sharing
+/// the same keyspace (e.g., one being a clone of the other). Use
+/// [`Tree::structurally_eq`] in order to compare [`Tree`]s whilst ignoring
+/// their keys.
+impl<K, V> PartialEq for Tree<K, V>
+where
+    K: Key,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.root_key == other.root_key
+            && self.inner_nodes.len() == other.inner_nodes.len()
+            && self.inner_nodes.iter().all(|(key, inner_node)| {
+                other.inner_nodes.get(key).is_some_and(|other_inner_node| {
+                    inner_node.parent_key == other_inner_node.parent_key
+                        && inner_node
+                            .child_keys
+                            .iter()
+                            .eq(&other_inner_node.child_keys)
+                        && inner_node.value == other_inner_node.value
+                })
+            })
+    }
+}
+
+impl<K, V> Eq for Tree<K, V>
+where
+    K: Key,
+    V: Eq,
+{
+}
+
+/// Immutably indexes into this [`Tree`] instance, returning the value
+/// corresponding to the given `key`.
+///
+/// # Panics:
+/// Panics if the given `key` does not exist in this [`Tree`] instance. Use
+/// [`Tree::get`] for a non-panicking alternative.
+impl<K, V> Index<K> for Tree<K, V>
+where
+    K: Key,
+{
+    type Output = V;
+
+    fn index(&self, key: K) -> &Self::Output {
+        match self.inner_nodes.get(key) {
+            Some(inner_node) => &inner_node.value,
+            None => panic!("the key {key:?} does not exist in this tree"),
+        }
+    }
+}
+
+/// Mutably indexes into this [`Tree`] instance, returning the value
+/// corresponding to the given `key`.
+///
+/// # Panics:
+/// Panics if the given `key` does not exist in this [`Tree`] instance. Use
+/// [`Tree::get_mut`] for a non-panicking alternative.
+impl<K, V> IndexMut<K> for Tree<K, V>
+where
+    K: Key,
+{
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        match self.inner_nodes.get_mut(key) {
+            Some(inner_node) => &mut inner_node.value,
+            None => panic!("the key {key:?} does not exist in this tree"),
+        }
+    }
+}
+
+/// Serializes this [`Tree`] as a sequence of `(parent_index, value)` pairs in
+/// BFS order.
+///
+/// The root is always the first entry (with a `parent_index` of [`None`]), and
+/// every other entry's `parent_index` points to an earlier entry. Siblings
+/// appear in the same order as they do inside of their parent's `child_keys`.
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for Tree<K, V>
+where
+    K: Key,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut indices = SecondaryMap::with_capacity(self.inner_nodes.len());
+
+        for (index, (key, _)) in self.bfs_with_parent().enumerate() {
+            indices.insert(key, index);
+        }
+
+        serializer.collect_seq(self.bfs_with_parent().map(|(key, parent_key)| {
+            let parent_index = parent_key.map(|parent_key| *indices.get(parent_key).unwrap());
+            let value = &self.inner_nodes.get(key).unwrap().value;
+            (parent_index, value)
+        }))
+    }
+}
+
+/// Deserializes a [`Tree`] from the sequence of `(parent_index, value)` pairs
+/// produced by its [`serde::Serialize`] implementation.
+///
+/// # Note:
+/// The keys of the deserialized [`Tree`] are freshly minted; they are not
+/// (and cannot be) the same keys as the ones of the serialized [`Tree`].
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for Tree<K, V>
+where
+    K: Key,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let entries = Vec::<(Option<usize>, V)>::deserialize(deserializer)?;
+
+        if entries.is_empty() {
+            Ok(Tree::default())
+        }
+        else {
+            let records = entries
+                .into_iter()
+                .map(|(parent_index, value)| (value, parent_index));
+
+            Tree::from_parented(records).ok_or_else(|| {
+                D::Error::custom(
+                    "expected a single root entry followed by entries whose parent entries \
+                     precede them",
+                )
+            })
+        }
+    }
+}
+
+/// An internal container over the underlying value inside of this [`Tree`]
+/// instance.
+///
+/// It contains the actual underlying value, as well as its `parent_key` and
+/// `child_keys`.
+#[derive(Clone)]
+struct InnerNode<K, V> {
+    /// The parent key of this value.
+    ///
+    /// Is [`None`] iff this value is the root value.
+    parent_key: Option<K>,
+
+    /// The children keys of this value.
+    child_keys: KeySet<K>,
+
+    /// The actual underlying value that is stored.
+    value: V,
+}
+
+/// An immutable container over the underlying value inside of this [`Tree`]
+/// instance as well as some other relevant information.
+#[derive(Clone, Copy)]
+pub struct Node<'a, K, V> {
+    /// The parent key of this value.
+    ///
+    /// Is [`None`] iff this value is the root value.
+    pub parent_key: Option<K>,
+
+    /// An immutable reference to the children keys of this value.
+    pub child_keys: &'a KeySet<K>,
+
+    /// An immutable reference to the underlying value that is stored.
+    pub value: &'a V,
+}
+
+/// A mutable container over the underlying value inside of this [`Tree`]
+/// instance as well as some other relevant information.
+pub struct NodeMut<'a, K, V> {
+    /// The parent key of this value.
+    ///
+    /// Is [`None`] iff this value is the root value.
+    pub parent_key: Option<K>,
+
+    /// An immutable reference to the children keys of this value.
+    pub child_keys: &'a KeySet<K>,
+
+    /// A mutable reference to the underlying value that is stored.
+    pub value: &'a mut V,
+}
+
+/// A handle to the root of a [`Tree`] instance, which may or may not exist.
+///
+/// Created by [`Tree::root_entry`].
+pub struct RootEntry<'a, K, V>
+where
+    K: Key,
+{
+    tree: &'a mut Tree<K, V>,
+}
+
+impl<'a, K, V> RootEntry<'a, K, V>
+where
+    K: Key,
+{
+    /// Inserts the given `value` as the root if the [`Tree`] is empty.
+    ///
+    /// Returns a mutable reference to the (possibly newly inserted) root.
+    pub fn or_insert(self, value: V) -> NodeMut<'a, K, V> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Inserts the value returned by `f` as the root if the [`Tree`] is empty.
+    /// Otherwise, `f` is never called.
+    ///
+    /// Returns a mutable reference to the (possibly newly inserted) root.
+    pub fn or_insert_with<F>(self, f: F) -> NodeMut<'a, K, V>
+    where
+        F: FnOnce() -> V,
+    {
+        let root_key = match self.tree.root_key {
+            Some(root_key) => root_key,
+            None => self.tree.insert_root(f()),
+        };
+
+        self.tree.get_mut(root_key).unwrap()
+    }
+}
+
+/// An iterator over all the keys inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::keys`].
+pub struct Keys<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::Keys<'a, K, InnerNode<K, V>>,
+}
+
+impl<K, V> Iterator for Keys<'_, K, V>
+where
+    K: Key,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> where K: Key {}
+
+/// An iterator over all the [`Node`]s inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::nodes`].
+pub struct Nodes<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::Values<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Nodes<'a, K, V>
+where
+    K: Key,
+{
+    type Item = Node<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|inner_node| Node {
+            parent_key: inner_node.parent_key,
+            child_keys: &inner_node.child_keys,
+            value: &inner_node.value,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Nodes<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for Nodes<'_, K, V> where K: Key {}
+
+/// An iterator over all the [`NodeMut`]s inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::nodes_mut`].
+pub struct NodesMut<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::ValuesMut<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for NodesMut<'a, K, V>
+where
+    K: Key,
+{
+    type Item = NodeMut<'a, K, V>;
 
-        both_keys_exist.then(|| get_relationship(self, key_1, key_2))
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|inner_node| NodeMut {
+            parent_key: inner_node.parent_key,
+            child_keys: &inner_node.child_keys,
+            value: &mut inner_node.value,
+        })
     }
 
-    // Iter methods:
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
 
-    /// Returns an owned iterator over all the keys inside of this [`Tree`]
-    /// instance.
-    pub fn keys(&self) -> impl '_ + Iterator<Item = K> {
-        self.inner_nodes.keys()
+impl<K, V> ExactSizeIterator for NodesMut<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for NodesMut<'_, K, V> where K: Key {}
+
+/// An iterator over all the values inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::values`].
+pub struct Values<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::Values<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V>
+where
+    K: Key,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|inner_node| &inner_node.value)
     }
 
-    /// Returns an immutable iterator over all the [`Node`]s inside of this
-    /// [`Tree`] instance.
-    pub fn nodes(&self) -> impl Iterator<Item = Node<'_, K, V>> {
-        self.inner_nodes.values().map(|inner_node| Node {
-            parent_key: inner_node.parent_key,
-            child_keys: &inner_node.child_keys,
-            value: &inner_node.value,
-        })
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
+}
 
-    /// Returns a mutable iterator over all the [`Node`]s inside of this
-    /// [`Tree`] instance.
-    pub fn nodes_mut(&mut self) -> impl Iterator<Item = NodeMut<'_, K, V>> {
-        self.inner_nodes.values_mut().map(|inner_node| NodeMut {
-            parent_key: inner_node.parent_key,
-            child_keys: &inner_node.child_keys,
-            value: &mut inner_node.value,
-        })
+impl<K, V> ExactSizeIterator for Values<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> where K: Key {}
+
+/// A mutable iterator over all the values inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::values_mut`].
+pub struct ValuesMut<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::ValuesMut<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V>
+where
+    K: Key,
+{
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|inner_node| &mut inner_node.value)
     }
 
-    /// Create an immutable iterator over the key-value pairs inside of this
-    /// [`Tree`] instance.
-    ///
-    /// The order of iteration is arbitrary. It will not be guaranteed to be
-    /// depth-first, breadth-first, in-order, etc.
-    pub fn iter(&self) -> impl Iterator<Item = (K, Node<'_, K, V>)> {
-        self.inner_nodes.iter().map(|(key, inner_node)| {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for ValuesMut<'_, K, V> where K: Key {}
+
+/// An iterator over all the key-[`Node`] pairs inside of a [`Tree`] instance.
+///
+/// Created by [`Tree::iter`].
+pub struct Iter<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::Iter<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V>
+where
+    K: Key,
+{
+    type Item = (K, Node<'a, K, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, inner_node)| {
             (
                 key,
                 Node {
@@ -647,17 +3761,34 @@ where
         })
     }
 
-    /// Create a mutable iterator over the key-value pairs inside of this
-    /// [`Tree`] instance.
-    ///
-    /// Note that this iterator will yield elements of type `(K, &mut V)`.
-    /// Namely, this function only provides mutable access to the values, not
-    /// the keys!
-    ///
-    /// The order of iteration is arbitrary. It will not be guaranteed to be
-    /// depth-first, breadth-first, in-order, etc.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, NodeMut<'_, K, V>)> {
-        self.inner_nodes.iter_mut().map(|(key, inner_node)| {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> where K: Key {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> where K: Key {}
+
+/// An iterator over all the key-[`NodeMut`] pairs inside of a [`Tree`]
+/// instance.
+///
+/// Created by [`Tree::iter_mut`].
+pub struct IterMut<'a, K, V>
+where
+    K: Key,
+{
+    inner: slotmap::basic::IterMut<'a, K, InnerNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V>
+where
+    K: Key,
+{
+    type Item = (K, NodeMut<'a, K, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, inner_node)| {
             (
                 key,
                 NodeMut {
@@ -668,69 +3799,15 @@ where
             )
         })
     }
-}
 
-impl<K, V> Default for Tree<K, V>
-where
-    K: Key,
-{
-    fn default() -> Self {
-        Self {
-            root_key: None,
-            inner_nodes: SlotMap::default(),
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
 }
 
-/// An internal container over the underlying value inside of this [`Tree`]
-/// instance.
-///
-/// It contains the actual underlying value, as well as its `parent_key` and
-/// `child_keys`.
-#[derive(Clone)]
-struct InnerNode<K, V> {
-    /// The parent key of this value.
-    ///
-    /// Is [`None`] iff this value is the root value.
-    parent_key: Option<K>,
-
-    /// The children keys of this value.
-    child_keys: IndexSet<K>,
-
-    /// The actual underlying value that is stored.
-    value: V,
-}
-
-/// An immutable container over the underlying value inside of this [`Tree`]
-/// instance as well as some other relevant information.
-#[derive(Clone, Copy)]
-pub struct Node<'a, K, V> {
-    /// The parent key of this value.
-    ///
-    /// Is [`None`] iff this value is the root value.
-    pub parent_key: Option<K>,
-
-    /// An immutable reference to the children keys of this value.
-    pub child_keys: &'a IndexSet<K>,
-
-    /// An immutable reference to the underlying value that is stored.
-    pub value: &'a V,
-}
-
-/// A mutable container over the underlying value inside of this [`Tree`]
-/// instance as well as some other relevant information.
-pub struct NodeMut<'a, K, V> {
-    /// The parent key of this value.
-    ///
-    /// Is [`None`] iff this value is the root value.
-    pub parent_key: Option<K>,
-
-    /// An immutable reference to the children keys of this value.
-    pub child_keys: &'a IndexSet<K>,
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> where K: Key {}
 
-    /// A mutable reference to the underlying value that is stored.
-    pub value: &'a mut V,
-}
+impl<K, V> FusedIterator for IterMut<'_, K, V> where K: Key {}
 
 /// A description of the relationship between two keys in a [`Tree`] instance.
 ///
@@ -767,3 +3844,379 @@ pub enum Relationship<K> {
         common_ancestor_key: K,
     },
 }
+
+/// An error that can occur when building a [`Tree`] instance out of some
+/// external representation (e.g., [`Tree::from_parent_pointers`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError<K> {
+    /// None of the given values were marked as the root.
+    NoRoot,
+
+    /// More than one of the given values was marked as the root.
+    MultipleRoots,
+
+    /// The `parent_key` of the value at `key` does not refer to any of the
+    /// given values.
+    MissingParent {
+        /// The key whose `parent_key` could not be found.
+        key: K,
+
+        /// The `parent_key` that could not be found.
+        parent_key: K,
+    },
+
+    /// The value at `key` is its own ancestor (i.e., traversing up its parental
+    /// lineage never reaches the root).
+    Cycle {
+        /// A key which lies on the cycle.
+        key: K,
+    },
+}
+
+impl<K> fmt::Display for BuildError<K>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRoot => write!(f, "no root value was found"),
+            Self::MultipleRoots => write!(f, "more than one root value was found"),
+            Self::MissingParent { key, parent_key } => {
+                write!(f, "the parent {parent_key:?} of {key:?} does not exist")
+            }
+            Self::Cycle { key } => write!(f, "{key:?} lies on a cycle"),
+        }
+    }
+}
+
+impl<K> Error for BuildError<K> where K: fmt::Debug {}
+
+/// An error describing a broken internal invariant of a [`Tree`] instance (see
+/// [`Tree::validate`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError<K> {
+    /// More than one value has no parent.
+    MultipleRoots,
+
+    /// The `root_key` does not refer to the (only) value which has no parent.
+    RootMismatch {
+        /// The `root_key` of the [`Tree`] instance.
+        root_key: Option<K>,
+    },
+
+    /// The `parent_key` of the value at `key` does not exist.
+    MissingParent {
+        /// The key whose `parent_key` could not be found.
+        key: K,
+
+        /// The `parent_key` that could not be found.
+        parent_key: K,
+    },
+
+    /// The `child_keys` of the value at `key` contain a key which does not
+    /// exist.
+    MissingChild {
+        /// The key whose `child_keys` contain the missing key.
+        key: K,
+
+        /// The child key that could not be found.
+        child_key: K,
+    },
+
+    /// The `parent_key` of the value at `key` and the `child_keys` of the value
+    /// at `parent_key` disagree (i.e., only one of them refers to the other).
+    ParentMismatch {
+        /// The (supposed) child key.
+        key: K,
+
+        /// The (supposed) parent key.
+        parent_key: K,
+    },
+
+    /// The value at `key` is its own ancestor.
+    Cycle {
+        /// A key which lies on the cycle.
+        key: K,
+    },
+}
+
+impl<K> fmt::Display for TreeError<K>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MultipleRoots => write!(f, "more than one value has no parent"),
+            Self::RootMismatch { root_key } => {
+                write!(
+                    f,
+                    "the root key {root_key:?} is not the only value without a parent"
+                )
+            }
+            Self::MissingParent { key, parent_key } => {
+                write!(f, "the parent {parent_key:?} of {key:?} does not exist")
+            }
+            Self::MissingChild { key, child_key } => {
+                write!(f, "the child {child_key:?} of {key:?} does not exist")
+            }
+            Self::ParentMismatch { key, parent_key } => {
+                write!(
+                    f,
+                    "{key:?} and its parent {parent_key:?} do not refer to each other"
+                )
+            }
+            Self::Cycle { key } => write!(f, "{key:?} lies on a cycle"),
+        }
+    }
+}
+
+impl<K> Error for TreeError<K> where K: fmt::Debug {}
+
+/// A simple (FNV-1a) [`Hasher`] used by every [`IndexSet`] inside of this
+/// crate (e.g., the `child_keys` of each value) when the `std` feature is
+/// disabled.
+///
+/// When the `std` feature is enabled, the default
+/// [`RandomState`](https://doc.rust-lang.org/std/collections/hash_map/struct.RandomState.html)
+/// is used instead.
+///
+/// # Note:
+/// This hasher is *not* resistant against HashDoS attacks. This is not a
+/// concern for the keys of a [`Tree`] (which are minted by its [`SlotMap`]),
+/// but when the `std` feature is disabled, [`Tree::intern`] also hashes the
+/// values themselves with it, so avoid interning attacker-controlled values in
+/// such builds. (When the `std` feature is enabled, [`Tree::intern`] uses a
+/// randomly seeded hasher instead; use [`Tree::intern_with_hasher`] to pick a
+/// custom one.)
+#[derive(Debug, Clone, Copy)]
+pub struct KeyHasher(u64);
+
+impl Default for KeyHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// The [`BuildHasher`] which builds [`KeyHasher`]s.
+pub type KeyBuildHasher = BuildHasherDefault<KeyHasher>;
+
+/// The set type which stores the `child_keys` of each value inside of a
+/// [`Tree`] instance.
+#[cfg(feature = "std")]
+pub type KeySet<K> = IndexSet<K>;
+
+/// The set type which stores the `child_keys` of each value inside of a
+/// [`Tree`] instance.
+#[cfg(not(feature = "std"))]
+pub type KeySet<K> = IndexSet<K, KeyBuildHasher>;
+
+/// The [`BuildHasher`] used by every [`IndexSet`] inside of a [`Tree`]
+/// instance.
+#[cfg(feature = "std")]
+type HashState = std::collections::hash_map::RandomState;
+
+/// The [`BuildHasher`] used by every [`IndexSet`] inside of a [`Tree`]
+/// instance.
+#[cfg(not(feature = "std"))]
+type HashState = KeyBuildHasher;
+
+/// Creates an empty [`IndexSet`] with room for at least `capacity` values.
+fn index_set_with_capacity<T>(capacity: usize) -> IndexSet<T, HashState> {
+    IndexSet::with_capacity_and_hasher(capacity, HashState::default())
+}
+
+/// Unwraps a [`Result`] which can never be an error.
+fn into_ok<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => match error {},
+    }
+}
+
+/// A [`SlotMap`] of `(parent_key, value)` pairs, as consumed by
+/// [`Tree::from_parent_pointers`] and produced by
+/// [`Tree::into_parent_pointers`].
+pub type ParentPointers<K, V> = SlotMap<K, (Option<K>, V)>;
+
+/// Replaces `old_key` with `new_key` inside of `child_keys`, keeping the index
+/// at which `old_key` was found.
+fn replace_child_key<K>(child_keys: &mut KeySet<K>, old_key: K, new_key: K)
+where
+    K: Key,
+{
+    let index = child_keys.get_index_of(&old_key).unwrap();
+    child_keys.insert(new_key);
+    child_keys.swap_indices(index, child_keys.len() - 1);
+    child_keys.pop();
+}
+
+#[cfg(test)]
+mod tests {
+    use slotmap::DefaultKey;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_cycle_with_acyclic_trees() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+        assert!(tree.detect_cycle().is_none());
+
+        let root_key = tree.insert_root(0);
+        assert!(tree.detect_cycle().is_none());
+
+        let child_key = tree.insert(1, root_key).unwrap();
+        tree.insert(2, child_key).unwrap();
+        tree.insert(3, root_key).unwrap();
+        assert!(tree.detect_cycle().is_none());
+    }
+
+    #[test]
+    fn test_detect_cycle_with_cyclic_tree() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+
+        let root_key = tree.insert_root(0);
+        let child_key = tree.insert(1, root_key).unwrap();
+        let cycle_key_1 = tree.insert(2, child_key).unwrap();
+        let cycle_key_2 = tree.insert(3, cycle_key_1).unwrap();
+
+        tree.inner_nodes.get_mut(cycle_key_1).unwrap().parent_key = Some(cycle_key_2);
+
+        let cycle_key = tree.detect_cycle().unwrap();
+        assert!(cycle_key == cycle_key_1 || cycle_key == cycle_key_2);
+    }
+
+    #[test]
+    fn test_validate_with_valid_trees() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+        assert_eq!(tree.validate(), Ok(()));
+
+        let root_key = tree.insert_root(0);
+        let child_key = tree.insert(1, root_key).unwrap();
+        tree.insert(2, child_key).unwrap();
+        assert_eq!(tree.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_with_broken_root() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+        let root_key = tree.insert_root(0);
+        let child_key = tree.insert(1, root_key).unwrap();
+
+        tree.root_key = Some(child_key);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeError::RootMismatch {
+                root_key: Some(child_key),
+            }),
+        );
+
+        tree.root_key = None;
+        assert_eq!(
+            tree.validate(),
+            Err(TreeError::RootMismatch { root_key: None }),
+        );
+
+        tree.root_key = Some(root_key);
+        tree.inner_nodes.insert(InnerNode {
+            parent_key: None,
+            child_keys: IndexSet::default(),
+            value: 2,
+        });
+        assert_eq!(tree.validate(), Err(TreeError::MultipleRoots));
+    }
+
+    #[test]
+    fn test_validate_with_broken_links() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+        let root_key = tree.insert_root(0);
+        let child_key = tree.insert(1, root_key).unwrap();
+        let grandchild_key = tree.insert(2, child_key).unwrap();
+
+        tree.inner_nodes.get_mut(grandchild_key).unwrap().parent_key = Some(root_key);
+        let error = tree.validate().unwrap_err();
+        assert!(
+            error
+                == TreeError::ParentMismatch {
+                    key: grandchild_key,
+                    parent_key: root_key,
+                }
+                || error
+                    == TreeError::ParentMismatch {
+                        key: grandchild_key,
+                        parent_key: child_key,
+                    }
+        );
+
+        tree.inner_nodes.get_mut(grandchild_key).unwrap().parent_key = Some(child_key);
+        tree.remove(grandchild_key, None);
+        tree.inner_nodes
+            .get_mut(child_key)
+            .unwrap()
+            .child_keys
+            .insert(grandchild_key);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeError::MissingChild {
+                key: child_key,
+                child_key: grandchild_key,
+            }),
+        );
+
+        tree.inner_nodes
+            .get_mut(child_key)
+            .unwrap()
+            .child_keys
+            .clear();
+        tree.inner_nodes
+            .get_mut(root_key)
+            .unwrap()
+            .child_keys
+            .clear();
+        tree.inner_nodes.get_mut(child_key).unwrap().parent_key = Some(grandchild_key);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeError::MissingParent {
+                key: child_key,
+                parent_key: grandchild_key,
+            }),
+        );
+    }
+
+    #[test]
+    fn test_validate_with_cyclic_tree() {
+        let mut tree = Tree::<DefaultKey, usize>::default();
+        let root_key = tree.insert_root(0);
+        let cycle_key_1 = tree.insert(1, root_key).unwrap();
+        let cycle_key_2 = tree.insert(2, cycle_key_1).unwrap();
+
+        tree.inner_nodes
+            .get_mut(root_key)
+            .unwrap()
+            .child_keys
+            .shift_remove(&cycle_key_1);
+        tree.inner_nodes.get_mut(cycle_key_1).unwrap().parent_key = Some(cycle_key_2);
+        tree.inner_nodes
+            .get_mut(cycle_key_2)
+            .unwrap()
+            .child_keys
+            .insert(cycle_key_1);
+
+        let error = tree.validate().unwrap_err();
+        assert!(
+            error == TreeError::Cycle { key: cycle_key_1 }
+                || error == TreeError::Cycle { key: cycle_key_2 }
+        );
+    }
+}
@@ -0,0 +1,59 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_bfs_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.bfs(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_bfs_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let keys = tree
+        .bfs(root_key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec![root_key]);
+}
+
+#[test]
+fn test_bfs_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let values = tree
+        .bfs(root_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, (0..tree.len()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_bfs_with_subtree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    let values = tree
+        .bfs(child_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![1, 4, 5, 6, 13, 14, 15, 16, 17, 18, 19, 20, 21],);
+}
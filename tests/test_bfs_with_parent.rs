@@ -0,0 +1,61 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_bfs_with_parent_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.bfs_with_parent().count(), 0);
+}
+
+#[test]
+fn test_bfs_with_parent_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let actual = tree
+        .bfs_with_parent()
+        .map(|(key, parent_key)| (*tree.get(key).unwrap().value, parent_key))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (0, None),
+        (1, Some(root_key)),
+        (2, Some(root_key)),
+        (3, Some(root_key)),
+    ];
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_bfs_with_parent_yields_parents_before_children() {
+    let tree = medium();
+
+    let mut seen_keys = vec![];
+
+    for (index, (key, parent_key)) in tree.bfs_with_parent().enumerate() {
+        assert_eq!(parent_key, tree.get(key).unwrap().parent_key);
+
+        match parent_key {
+            Some(parent_key) => assert!(seen_keys.contains(&parent_key)),
+            None => assert_eq!(index, 0),
+        };
+
+        seen_keys.push(key);
+    }
+
+    assert_eq!(seen_keys.len(), tree.len());
+
+    let values = seen_keys
+        .iter()
+        .map(|&key| *tree.get(key).unwrap().value)
+        .collect::<Vec<_>>();
+    assert_eq!(values, (0..tree.len()).collect::<Vec<_>>());
+}
@@ -0,0 +1,39 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_build_secondary_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    let secondary_map = tree.build_secondary(|_, &value| value);
+
+    assert!(secondary_map.is_empty());
+}
+
+#[test]
+fn test_build_secondary_with_medium_tree() {
+    let tree = medium();
+
+    let secondary_map = tree.build_secondary(|_, &value| value * 2);
+
+    assert_eq!(secondary_map.len(), tree.len());
+
+    for (key, node) in tree.iter() {
+        assert_eq!(secondary_map[key], *node.value * 2);
+    }
+}
+
+#[test]
+fn test_build_secondary_passes_keys() {
+    let tree = medium();
+
+    let secondary_map = tree.build_secondary(|key, _| tree.parent_key(key).unwrap());
+
+    for (key, node) in tree.iter() {
+        assert_eq!(secondary_map[key], node.parent_key);
+    }
+}
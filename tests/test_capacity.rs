@@ -0,0 +1,47 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_capacity_with_with_capacity() {
+    let tree = Tree::<DefaultKey, usize>::with_capacity(10);
+
+    assert!(tree.capacity() >= 10);
+}
+
+#[test]
+fn test_capacity_is_at_least_len() {
+    let tree = medium();
+
+    assert!(tree.capacity() >= tree.len());
+}
+
+#[test]
+fn test_reserve_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    tree.reserve(100);
+
+    assert!(tree.is_empty());
+    assert!(tree.capacity() >= 100);
+}
+
+#[test]
+fn test_reserve_avoids_reallocation_during_burst() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    tree.reserve(50);
+    let capacity = tree.capacity();
+    assert!(capacity >= tree.len() + 50);
+
+    for value in 0..50 {
+        tree.insert(value, root_key).unwrap();
+    }
+
+    assert_eq!(tree.len(), 90);
+    assert_eq!(tree.capacity(), capacity);
+}
@@ -0,0 +1,38 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    large,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_child_count_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.child_count(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_child_count_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.child_count(root_key), Some(3));
+
+    for (leaf_key, _) in tree.leaves() {
+        assert_eq!(tree.child_count(leaf_key), Some(0));
+    }
+}
+
+#[test]
+fn test_child_count_with_large_tree() {
+    let tree = large();
+
+    for (key, node) in tree.iter() {
+        let expected_child_count = if *node.value < 21 { 4 } else { 0 };
+        assert_eq!(tree.child_count(key), Some(expected_child_count));
+    }
+}
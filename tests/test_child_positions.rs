@@ -0,0 +1,38 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    single_root_tree,
+};
+
+#[test]
+fn test_child_positions_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    assert!(tree.child_positions().is_empty());
+}
+
+#[test]
+fn test_child_positions_with_large_tree() {
+    let tree = large();
+    let root_key = tree.root_key().unwrap();
+
+    let child_positions = tree.child_positions();
+
+    assert_eq!(child_positions.len(), tree.len() - 1);
+    assert!(!child_positions.contains_key(root_key));
+
+    for (key, node) in tree.iter() {
+        if let Some(parent_key) = node.parent_key {
+            let expected_index = tree
+                .get(parent_key)
+                .unwrap()
+                .child_keys
+                .get_index_of(&key)
+                .unwrap();
+
+            assert_eq!(child_positions[key], expected_index);
+        };
+    }
+}
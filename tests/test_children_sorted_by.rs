@@ -0,0 +1,49 @@
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_children_sorted_by_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .children_sorted_by(DefaultKey::default(), |a, b| a.cmp(b))
+        .is_none());
+}
+
+#[test]
+fn test_children_sorted_by_does_not_mutate_child_keys() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.insert_root(0);
+    let child_key_3 = tree.insert(3, root_key).unwrap();
+    let child_key_1 = tree.insert(1, root_key).unwrap();
+    let child_key_2 = tree.insert(2, root_key).unwrap();
+
+    let sorted_keys = tree.children_sorted_by(root_key, |a, b| a.cmp(b)).unwrap();
+    assert_eq!(sorted_keys, vec![child_key_1, child_key_2, child_key_3]);
+
+    let reverse_sorted_keys = tree.children_sorted_by(root_key, |a, b| b.cmp(a)).unwrap();
+    assert_eq!(
+        reverse_sorted_keys,
+        vec![child_key_3, child_key_2, child_key_1]
+    );
+
+    let child_keys = tree
+        .get(root_key)
+        .unwrap()
+        .child_keys
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(child_keys, vec![child_key_3, child_key_1, child_key_2]);
+}
+
+#[test]
+fn test_children_sorted_by_on_leaf() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.insert_root(0);
+
+    let sorted_keys = tree.children_sorted_by(root_key, |a, b| a.cmp(b)).unwrap();
+    assert!(sorted_keys.is_empty());
+}
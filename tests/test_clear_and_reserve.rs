@@ -0,0 +1,52 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_clear_and_reserve_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    tree.clear_and_reserve(100);
+
+    assert!(tree.is_empty());
+    assert!(tree.root_key().is_none());
+    assert!(tree.capacity() >= 100);
+}
+
+#[test]
+fn test_clear_and_reserve_keeps_existing_allocation() {
+    let mut tree = large();
+    let capacity = tree.capacity();
+
+    tree.clear_and_reserve(10);
+
+    assert!(tree.is_empty());
+    assert!(tree.root_key().is_none());
+    assert_eq!(tree.capacity(), capacity);
+}
+
+#[test]
+fn test_clear_and_reserve_grows_allocation() {
+    let mut tree = medium();
+
+    tree.clear_and_reserve(200);
+
+    assert!(tree.is_empty());
+    assert!(tree.capacity() >= 200);
+
+    let capacity = tree.capacity();
+    let root_key = tree.insert_root(0);
+
+    for value in 1..200 {
+        tree.insert(value, root_key).unwrap();
+    }
+
+    assert_eq!(tree.len(), 200);
+    assert_eq!(tree.capacity(), capacity);
+}
@@ -0,0 +1,68 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn preorder_values(tree: &Tree<DefaultKey, usize>) -> Vec<usize> {
+    tree.preorder(tree.root_key().unwrap())
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect()
+}
+
+#[test]
+fn test_clone_subtree_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.clone_subtree(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_clone_subtree_with_root_key() {
+    let tree = depth_2_tree();
+
+    let cloned_tree = tree.clone_subtree(tree.root_key().unwrap()).unwrap();
+
+    assert_eq!(cloned_tree.len(), 4);
+    assert_eq!(preorder_values(&cloned_tree), preorder_values(&tree));
+}
+
+#[test]
+fn test_clone_subtree_with_leaf_key() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = tree.get(root_key).unwrap().child_keys[2];
+
+    let cloned_tree = tree.clone_subtree(leaf_key).unwrap();
+
+    assert_eq!(cloned_tree.len(), 1);
+    assert_eq!(preorder_values(&cloned_tree), vec![3]);
+}
+
+#[test]
+fn test_clone_subtree_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+
+    let cloned_tree = tree.clone_subtree(child_key).unwrap();
+
+    assert_eq!(cloned_tree.len(), 13);
+    assert_eq!(
+        preorder_values(&cloned_tree),
+        vec![2, 7, 22, 23, 24, 8, 25, 26, 27, 9, 28, 29, 30],
+    );
+    assert!(cloned_tree
+        .get(cloned_tree.root_key().unwrap())
+        .unwrap()
+        .parent_key
+        .is_none());
+
+    assert_eq!(tree.len(), 40);
+    assert_eq!(preorder_values(&tree).len(), 40);
+}
@@ -0,0 +1,67 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_common_ancestor_with_non_existent_keys() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .common_ancestor(DefaultKey::default(), DefaultKey::default())
+        .is_none());
+
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree
+        .common_ancestor(root_key, DefaultKey::default())
+        .is_none());
+    assert!(tree
+        .common_ancestor(DefaultKey::default(), root_key)
+        .is_none());
+}
+
+#[test]
+fn test_common_ancestor_with_same_key() {
+    let tree = linear_depth_4_tree();
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert_eq!(tree.common_ancestor(leaf_key, leaf_key), Some(leaf_key));
+}
+
+#[test]
+fn test_common_ancestor_with_ancestral_keys() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert_eq!(tree.common_ancestor(root_key, leaf_key), Some(root_key));
+    assert_eq!(tree.common_ancestor(leaf_key, child_key), Some(child_key));
+}
+
+#[test]
+fn test_common_ancestor_with_siblings() {
+    let tree = medium();
+    let key_of = |value| tree.key_of(&value).unwrap();
+
+    assert_eq!(
+        tree.common_ancestor(key_of(13), key_of(14)),
+        Some(key_of(4))
+    );
+    assert_eq!(
+        tree.common_ancestor(key_of(13), key_of(16)),
+        Some(key_of(1))
+    );
+    assert_eq!(
+        tree.common_ancestor(key_of(13), key_of(39)),
+        Some(key_of(0))
+    );
+    assert_eq!(tree.common_ancestor(key_of(5), key_of(2)), Some(key_of(0)));
+}
@@ -0,0 +1,36 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_count_matching_at_depth_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.count_matching_at_depth(0, |_| true), 0);
+}
+
+#[test]
+fn test_count_matching_at_depth_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+
+    assert_eq!(tree.count_matching_at_depth(0, |_| true), 1);
+    assert_eq!(tree.count_matching_at_depth(3, |&value| value == 3), 1);
+    assert_eq!(tree.count_matching_at_depth(3, |&value| value == 2), 0);
+    assert_eq!(tree.count_matching_at_depth(4, |_| true), 0);
+}
+
+#[test]
+fn test_count_matching_at_depth_with_medium_tree() {
+    let tree = medium();
+
+    assert_eq!(tree.count_matching_at_depth(1, |_| true), 3);
+    assert_eq!(tree.count_matching_at_depth(2, |value| value % 2 == 0), 5);
+    assert_eq!(tree.count_matching_at_depth(3, |value| value % 3 == 0), 9);
+    assert_eq!(tree.count_matching_at_depth(3, |&value| value < 13), 0);
+}
@@ -0,0 +1,35 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_count_where_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.count_where(|_| true), 0);
+}
+
+#[test]
+fn test_count_where_with_medium_tree() {
+    let tree = medium();
+
+    assert_eq!(tree.count_where(|_| true), 40);
+    assert_eq!(tree.count_where(|_| false), 0);
+    assert_eq!(tree.count_where(|&value| value >= 13), 27);
+    assert_eq!(tree.count_where(|&value| value % 2 == 0), 20);
+}
+
+#[test]
+fn test_count_where_with_large_tree() {
+    let tree = large();
+
+    assert_eq!(tree.count_where(|&value| value >= 21), 64);
+    assert_eq!(tree.count_where(|&value| value == 84), 1);
+    assert_eq!(tree.count_where(|&value| value == 85), 0);
+}
@@ -0,0 +1,40 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::single_root_tree;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_debug_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(format!("{tree:?}"), "Tree(empty)");
+}
+
+#[test]
+fn test_debug_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(format!("{tree:?}"), format!("Tree\n    {root_key:?}: 0"));
+}
+
+#[test]
+fn test_debug_with_nested_tree() {
+    let mut tree = Tree::<DefaultKey, &str>::default();
+    let root_key = tree.insert_root("a");
+    let child_key_1 = tree.insert("b", root_key).unwrap();
+    let grandchild_key = tree.insert("c", child_key_1).unwrap();
+    let child_key_2 = tree.insert("d", root_key).unwrap();
+
+    let expected = format!(
+        "Tree
+    {root_key:?}: \"a\"
+        {child_key_1:?}: \"b\"
+            {grandchild_key:?}: \"c\"
+        {child_key_2:?}: \"d\""
+    );
+
+    assert_eq!(format!("{tree:?}"), expected);
+}
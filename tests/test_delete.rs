@@ -0,0 +1,72 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_delete_from_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.delete(0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_delete_a_non_existent_key() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [] }));
+
+    assert!(!declarative_tree.delete(100));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_delete_root_in_a_multi_element_tree() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 2, 'c', [] }
+        ] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert!(declarative_tree.delete(0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_delete_subtree_in_a_multi_element_tree() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 2, 'c', [
+                node! { 3, 'd', [] },
+            ] },
+            node! { 4, 'e', [] },
+        ] },
+        node! { 5, 'f', [] },
+    ] }));
+
+    assert!(declarative_tree.delete(2));
+    assert!(!declarative_tree.delete(2));
+    assert!(!declarative_tree.delete(3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 4, 'e', [] },
+        ] },
+        node! { 5, 'f', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
@@ -0,0 +1,46 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    linear_depth_4_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_depth_map_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.depth_map().is_empty());
+}
+
+#[test]
+fn test_depth_map_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+
+    let depth_map = tree.depth_map();
+
+    assert_eq!(depth_map.len(), 4);
+    for (key, node) in tree.iter() {
+        assert_eq!(depth_map[key], *node.value);
+    }
+}
+
+#[test]
+fn test_depth_map_with_large_tree() {
+    let tree = large();
+
+    let depth_map = tree.depth_map();
+
+    assert_eq!(depth_map.len(), 85);
+    for (key, node) in tree.iter() {
+        let expected_depth = match *node.value {
+            0 => 0,
+            1..=4 => 1,
+            5..=20 => 2,
+            _ => 3,
+        };
+        assert_eq!(depth_map[key], expected_depth);
+    }
+}
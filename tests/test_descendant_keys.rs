@@ -0,0 +1,64 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_descendant_keys_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.descendant_keys(DefaultKey::default(), None).is_none());
+    assert!(tree
+        .descendant_keys_inclusive(DefaultKey::default(), None)
+        .is_none());
+}
+
+#[test]
+fn test_descendant_keys_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.descendant_keys(root_key, None), Some(vec![]));
+    assert_eq!(
+        tree.descendant_keys_inclusive(root_key, None),
+        Some(vec![root_key]),
+    );
+}
+
+#[test]
+fn test_descendant_keys_with_subtree_of_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    let mut descendant_values = tree
+        .descendant_keys(child_key, Some(12))
+        .unwrap()
+        .into_iter()
+        .map(|key| *tree.get(key).unwrap().value)
+        .collect::<Vec<_>>();
+    descendant_values.sort();
+
+    assert_eq!(
+        descendant_values,
+        vec![4, 5, 6, 13, 14, 15, 16, 17, 18, 19, 20, 21],
+    );
+
+    let mut inclusive_descendant_values = tree
+        .descendant_keys_inclusive(child_key, None)
+        .unwrap()
+        .into_iter()
+        .map(|key| *tree.get(key).unwrap().value)
+        .collect::<Vec<_>>();
+    inclusive_descendant_values.sort();
+
+    assert_eq!(
+        inclusive_descendant_values,
+        vec![1, 4, 5, 6, 13, 14, 15, 16, 17, 18, 19, 20, 21],
+    );
+}
@@ -0,0 +1,76 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn preorder_values(tree: &Tree<DefaultKey, usize>) -> Vec<usize> {
+    tree.preorder(tree.root_key().unwrap())
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect()
+}
+
+#[test]
+fn test_detach_subtree_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.detach_subtree(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_detach_subtree_with_root_key() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let detached_tree = tree.detach_subtree(root_key).unwrap();
+
+    assert!(tree.is_empty());
+    assert!(tree.root_key().is_none());
+    assert_eq!(detached_tree.root_key(), Some(root_key));
+    assert_eq!(preorder_values(&detached_tree), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_detach_subtree_with_leaf_key() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = tree.get(root_key).unwrap().child_keys[1];
+
+    let detached_tree = tree.detach_subtree(leaf_key).unwrap();
+
+    assert_eq!(preorder_values(&detached_tree), vec![2]);
+    assert!(!tree.contains(leaf_key));
+    assert_eq!(preorder_values(&tree), vec![0, 1, 3]);
+}
+
+#[test]
+fn test_detach_subtree_with_medium_tree() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+    let detached_keys = tree.descendant_keys_inclusive(child_key, None).unwrap();
+
+    let detached_tree = tree.detach_subtree(child_key).unwrap();
+
+    assert_eq!(detached_tree.len(), 13);
+    assert_eq!(
+        preorder_values(&detached_tree),
+        vec![2, 7, 22, 23, 24, 8, 25, 26, 27, 9, 28, 29, 30],
+    );
+
+    assert_eq!(tree.len(), 27);
+    assert!(detached_keys.into_iter().all(|key| !tree.contains(key)));
+    assert_eq!(tree.get(root_key).unwrap().child_keys.len(), 2);
+    assert_eq!(
+        preorder_values(&tree),
+        vec![
+            0, 1, 4, 13, 14, 15, 5, 16, 17, 18, 6, 19, 20, 21, 3, 10, 31, 32, 33, 11, 34, 35, 36,
+            12, 37, 38, 39,
+        ],
+    );
+}
@@ -0,0 +1,72 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_diameter_path_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.diameter_path().is_empty());
+}
+
+#[test]
+fn test_diameter_path_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    assert_eq!(tree.diameter_path(), vec![tree.root_key().unwrap()]);
+}
+
+#[test]
+fn test_diameter_path_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+
+    let values = tree
+        .diameter_path()
+        .into_iter()
+        .map(|key| *tree.get(key).unwrap().value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_diameter_path_with_unbalanced_tree() {
+    // 0
+    // |-- 1
+    // |-- 2
+    //     |-- 3
+    //         |-- 4
+    //             |-- 5
+    //         |-- 6
+    //     |-- 7
+    //         |-- 8
+    //             |-- 9
+    //                 |-- 10
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let key_0 = tree.insert_root(0);
+    tree.insert(1, key_0).unwrap();
+    let key_2 = tree.insert(2, key_0).unwrap();
+    let key_3 = tree.insert(3, key_2).unwrap();
+    let key_4 = tree.insert(4, key_3).unwrap();
+    tree.insert(5, key_4).unwrap();
+    tree.insert(6, key_3).unwrap();
+    let key_7 = tree.insert(7, key_2).unwrap();
+    let key_8 = tree.insert(8, key_7).unwrap();
+    let key_9 = tree.insert(9, key_8).unwrap();
+    tree.insert(10, key_9).unwrap();
+
+    let values = tree
+        .diameter_path()
+        .into_iter()
+        .map(|key| *tree.get(key).unwrap().value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![10, 9, 8, 7, 2, 3, 4, 5]);
+}
@@ -0,0 +1,56 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_distance_with_non_existent_keys() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .distance(DefaultKey::default(), DefaultKey::default())
+        .is_none());
+
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.distance(root_key, DefaultKey::default()).is_none());
+    assert!(tree.distance(DefaultKey::default(), root_key).is_none());
+}
+
+#[test]
+fn test_distance_with_same_key() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.distance(root_key, root_key), Some(0));
+}
+
+#[test]
+fn test_distance_with_ancestral_keys() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert_eq!(tree.distance(root_key, child_key), Some(1));
+    assert_eq!(tree.distance(root_key, leaf_key), Some(3));
+    assert_eq!(tree.distance(leaf_key, root_key), Some(3));
+    assert_eq!(tree.distance(leaf_key, child_key), Some(2));
+}
+
+#[test]
+fn test_distance_with_siblings() {
+    let tree = medium();
+    let key_of = |value| tree.key_of(&value).unwrap();
+
+    assert_eq!(tree.distance(key_of(13), key_of(14)), Some(2));
+    assert_eq!(tree.distance(key_of(13), key_of(16)), Some(4));
+    assert_eq!(tree.distance(key_of(13), key_of(39)), Some(6));
+    assert_eq!(tree.distance(key_of(13), key_of(2)), Some(4));
+}
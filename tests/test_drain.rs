@@ -0,0 +1,47 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_drain_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.drain().count(), 0);
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_drain_with_medium_tree() {
+    let mut tree = medium();
+    let keys = tree.keys().collect::<Vec<_>>();
+
+    let mut drained = tree.drain().collect::<Vec<_>>();
+    drained.sort_by_key(|&(_, value)| value);
+
+    assert_eq!(
+        drained.iter().map(|&(_, value)| value).collect::<Vec<_>>(),
+        (0..40).collect::<Vec<_>>(),
+    );
+    assert!(drained.iter().all(|(key, _)| keys.contains(key)));
+
+    assert!(tree.is_empty());
+    assert!(tree.root_key().is_none());
+    assert!(keys.iter().all(|&key| !tree.contains(key)));
+}
+
+#[test]
+fn test_drain_dropped_early_still_empties_tree() {
+    let mut tree = medium();
+
+    assert!(tree.drain().next().is_some());
+
+    assert!(tree.is_empty());
+    assert!(tree.root_key().is_none());
+
+    let root_key = tree.insert_root(0);
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree.root_key(), Some(root_key));
+}
@@ -0,0 +1,58 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_edges_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.edges().count(), 0);
+}
+
+#[test]
+fn test_edges_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    assert_eq!(tree.edges().count(), 0);
+}
+
+#[test]
+fn test_edges_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys;
+
+    assert_eq!(
+        tree.edges().collect::<Vec<_>>(),
+        vec![
+            (root_key, child_keys[0]),
+            (root_key, child_keys[1]),
+            (root_key, child_keys[2]),
+        ],
+    );
+}
+
+#[test]
+fn test_edges_with_medium_tree() {
+    let tree = medium();
+
+    let values = tree
+        .edges()
+        .map(|(parent_key, child_key)| (tree[parent_key], tree[child_key]))
+        .collect::<Vec<_>>();
+
+    assert_eq!(values.len(), tree.len() - 1);
+    assert_eq!(
+        values,
+        (1..40)
+            .map(|value| ((value - 1) / 3, value))
+            .collect::<Vec<_>>(),
+    );
+}
@@ -0,0 +1,39 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_fill_subtree_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(!tree.fill_subtree(DefaultKey::default(), 100));
+}
+
+#[test]
+fn test_fill_subtree_with_subtree_of_medium_tree() {
+    let mut tree = medium();
+    let original_tree = tree.clone();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+
+    assert!(tree.fill_subtree(child_key, 100));
+
+    let subtree_keys = tree
+        .preorder(child_key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+    assert_eq!(subtree_keys.len(), 13);
+
+    for (key, node) in tree.iter() {
+        if subtree_keys.contains(&key) {
+            assert_eq!(*node.value, 100);
+        }
+        else {
+            assert_eq!(node.value, original_tree.get(key).unwrap().value);
+        };
+    }
+}
@@ -0,0 +1,40 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_find_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.find(|_| true).is_none());
+    assert!(tree.find_mut(|_| true).is_none());
+}
+
+#[test]
+fn test_find_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let (key, node) = tree.find(|&value| value == 2).unwrap();
+
+    assert_eq!(*node.value, 2);
+    assert_eq!(node.parent_key, Some(root_key));
+    assert_eq!(tree.get(root_key).unwrap().child_keys[1], key);
+
+    assert!(tree.find(|&value| value >= 40).is_none());
+}
+
+#[test]
+fn test_find_mut_with_medium_tree() {
+    let mut tree = medium();
+
+    let (key, node) = tree.find_mut(|&value| value == 17).unwrap();
+    *node.value = 100;
+
+    assert_eq!(*tree.get(key).unwrap().value, 100);
+    assert!(tree.find(|&value| value == 17).is_none());
+    assert!(tree.find_mut(|&value| value >= 101).is_none());
+}
@@ -0,0 +1,81 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_fold_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .fold(DefaultKey::default(), |value, _: Vec<usize>| *value)
+        .is_none());
+}
+
+#[test]
+fn test_fold_with_leaf_key() {
+    let tree = medium();
+    let (leaf_key, leaf_node) = tree.leaves().next().unwrap();
+
+    let accumulator = tree.fold(leaf_key, |&value, child_accumulators: Vec<usize>| {
+        assert!(child_accumulators.is_empty());
+        value
+    });
+
+    assert_eq!(accumulator, Some(*leaf_node.value));
+}
+
+#[test]
+fn test_fold_sums_subtrees() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    let sum = |&value: &usize, child_sums: Vec<usize>| value + child_sums.iter().sum::<usize>();
+
+    assert_eq!(tree.fold(root_key, sum), Some((0..40).sum()));
+    assert_eq!(
+        tree.fold(child_key, sum),
+        Some(1 + 4 + 5 + 6 + (13..22).sum::<usize>()),
+    );
+}
+
+#[test]
+fn test_fold_preserves_child_order() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let render = tree
+        .fold(root_key, |value, child_renders: Vec<String>| {
+            if child_renders.is_empty() {
+                value.to_string()
+            }
+            else {
+                format!("{value}({})", child_renders.join(" "))
+            }
+        })
+        .unwrap();
+
+    assert!(render.starts_with("0(1(4(13 14 15) 5(16 17 18) 6(19 20 21)) 2(7(22 23 24)"));
+    assert!(render.ends_with("12(37 38 39)))"));
+}
+
+#[test]
+fn test_fold_computes_height() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let height = tree.fold(root_key, |_, child_heights: Vec<usize>| {
+        child_heights
+            .into_iter()
+            .max()
+            .map_or(0, |height| height + 1)
+    });
+
+    assert_eq!(height, Some(3));
+}
@@ -0,0 +1,60 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+    single_root_tree,
+};
+
+#[test]
+fn test_for_each_edge_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    let mut edges = vec![];
+    tree.for_each_edge(|parent_key, child_key| edges.push((parent_key, child_key)));
+
+    assert!(edges.is_empty());
+}
+
+#[test]
+fn test_for_each_edge_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys;
+
+    let mut edges = vec![];
+    tree.for_each_edge(|parent_key, child_key| edges.push((parent_key, child_key)));
+
+    assert_eq!(edges.len(), tree.len() - 1);
+    assert_eq!(
+        edges,
+        vec![
+            (root_key, child_keys[0]),
+            (root_key, child_keys[1]),
+            (root_key, child_keys[2]),
+        ],
+    );
+}
+
+#[test]
+fn test_for_each_edge_with_medium_tree() {
+    let tree = medium();
+
+    let mut edges = vec![];
+    tree.for_each_edge(|parent_key, child_key| edges.push((parent_key, child_key)));
+
+    assert_eq!(edges.len(), tree.len() - 1);
+
+    let values = edges
+        .iter()
+        .take(4)
+        .map(|&(parent_key, child_key)| {
+            (
+                *tree.get(parent_key).unwrap().value,
+                *tree.get(child_key).unwrap().value,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![(0, 1), (1, 4), (4, 13), (4, 14)]);
+}
@@ -0,0 +1,30 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_for_each_value_mut_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let mut count = 0;
+
+    tree.for_each_value_mut(|_| count += 1);
+
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_for_each_value_mut_with_medium_tree() {
+    let mut tree = medium();
+    let mut count = 0;
+
+    tree.for_each_value_mut(|value| {
+        *value *= 10;
+        count += 1;
+    });
+
+    assert_eq!(count, 40);
+    assert!(tree.structurally_eq(&medium().map(|value| value * 10)));
+}
@@ -0,0 +1,142 @@
+use slotmap::{
+    DefaultKey,
+    SlotMap,
+};
+use tinytree::{
+    BuildError,
+    Tree,
+};
+
+#[test]
+fn test_from_parent_pointers_with_empty_map() {
+    let parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let error = Tree::from_parent_pointers(parent_pointers).err().unwrap();
+    assert_eq!(error, BuildError::NoRoot);
+}
+
+#[test]
+fn test_from_parent_pointers_with_valid_map() {
+    let mut parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let root_key = parent_pointers.insert((None, 'a'));
+    let child_key_1 = parent_pointers.insert((Some(root_key), 'b'));
+    let child_key_2 = parent_pointers.insert((Some(root_key), 'c'));
+    let grandchild_key = parent_pointers.insert((Some(child_key_2), 'd'));
+
+    let (tree, key_map) = Tree::from_parent_pointers(parent_pointers).unwrap();
+
+    assert_eq!(tree.len(), 4);
+    assert_eq!(key_map.len(), 4);
+    assert!(key_map.iter().all(|(old_key, &key)| old_key == key));
+    assert_eq!(tree.root_key(), Some(root_key));
+
+    let root_node = tree.get(root_key).unwrap();
+    assert_eq!(*root_node.value, 'a');
+    assert_eq!(root_node.parent_key, None);
+    assert_eq!(
+        root_node.child_keys.iter().copied().collect::<Vec<_>>(),
+        vec![child_key_1, child_key_2],
+    );
+
+    let child_node_2 = tree.get(child_key_2).unwrap();
+    assert_eq!(*child_node_2.value, 'c');
+    assert_eq!(child_node_2.parent_key, Some(root_key));
+    assert_eq!(
+        child_node_2.child_keys.iter().copied().collect::<Vec<_>>(),
+        vec![grandchild_key],
+    );
+
+    let grandchild_node = tree.get(grandchild_key).unwrap();
+    assert_eq!(*grandchild_node.value, 'd');
+    assert_eq!(grandchild_node.parent_key, Some(child_key_2));
+    assert!(grandchild_node.child_keys.is_empty());
+}
+
+#[test]
+fn test_from_parent_pointers_with_multiple_roots() {
+    let mut parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let root_key = parent_pointers.insert((None, 'a'));
+    parent_pointers.insert((Some(root_key), 'b'));
+    parent_pointers.insert((None, 'c'));
+
+    let error = Tree::from_parent_pointers(parent_pointers).err().unwrap();
+    assert_eq!(error, BuildError::MultipleRoots);
+}
+
+#[test]
+fn test_from_parent_pointers_with_missing_parent() {
+    let mut parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let root_key = parent_pointers.insert((None, 'a'));
+    let removed_key = parent_pointers.insert((Some(root_key), 'b'));
+    parent_pointers.remove(removed_key);
+    let child_key = parent_pointers.insert((Some(removed_key), 'c'));
+
+    let error = Tree::from_parent_pointers(parent_pointers).err().unwrap();
+    assert_eq!(
+        error,
+        BuildError::MissingParent {
+            key: child_key,
+            parent_key: removed_key,
+        },
+    );
+}
+
+#[test]
+fn test_from_parent_pointers_with_cycle() {
+    let mut parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let root_key = parent_pointers.insert((None, 'a'));
+    parent_pointers.insert((Some(root_key), 'b'));
+    let cycle_key_1 = parent_pointers.insert((None, 'c'));
+    let cycle_key_2 = parent_pointers.insert((Some(cycle_key_1), 'd'));
+    parent_pointers.get_mut(cycle_key_1).unwrap().0 = Some(cycle_key_2);
+
+    let error = Tree::from_parent_pointers(parent_pointers).err().unwrap();
+    assert!(matches!(
+        error,
+        BuildError::Cycle { key } if key == cycle_key_1 || key == cycle_key_2,
+    ));
+}
+
+#[test]
+fn test_from_parent_pointers_with_removal() {
+    let mut parent_pointers = SlotMap::<DefaultKey, (Option<DefaultKey>, char)>::default();
+
+    let removed_key = parent_pointers.insert((None, 'z'));
+    let root_key = parent_pointers.insert((None, 'a'));
+    parent_pointers.remove(removed_key);
+    let child_key_1 = parent_pointers.insert((Some(root_key), 'b'));
+    let child_key_2 = parent_pointers.insert((Some(root_key), 'c'));
+    let grandchild_key = parent_pointers.insert((Some(child_key_1), 'd'));
+
+    let (tree, key_map) = Tree::from_parent_pointers(parent_pointers).unwrap();
+
+    assert_eq!(tree.len(), 4);
+    assert_eq!(key_map.len(), 4);
+    assert!(!key_map.contains_key(removed_key));
+    assert_ne!(key_map[child_key_1], child_key_1);
+
+    let key_of = |old_key| key_map[old_key];
+
+    assert_eq!(tree.root_key(), Some(key_of(root_key)));
+    assert_eq!(tree[key_of(root_key)], 'a');
+    assert_eq!(tree[key_of(child_key_1)], 'b');
+    assert_eq!(tree[key_of(child_key_2)], 'c');
+    assert_eq!(tree[key_of(grandchild_key)], 'd');
+    assert_eq!(
+        tree.parent_key(key_of(grandchild_key)),
+        Some(Some(key_of(child_key_1))),
+    );
+    assert_eq!(
+        tree.get(key_of(root_key))
+            .unwrap()
+            .child_keys
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![key_of(child_key_1), key_of(child_key_2)],
+    );
+}
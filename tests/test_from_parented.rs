@@ -0,0 +1,60 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_from_parented_with_no_records() {
+    assert!(Tree::<DefaultKey, usize>::from_parented([]).is_none());
+}
+
+#[test]
+fn test_from_parented_with_invalid_records() {
+    // No root:
+    assert!(Tree::<DefaultKey, usize>::from_parented([(0, Some(0))]).is_none());
+
+    // Multiple roots:
+    assert!(Tree::<DefaultKey, usize>::from_parented([(0, None), (1, None)]).is_none());
+
+    // Root is not the first record:
+    assert!(Tree::<DefaultKey, usize>::from_parented([(1, Some(1)), (0, None)]).is_none());
+
+    // Dangling parent index:
+    assert!(Tree::<DefaultKey, usize>::from_parented([(0, None), (1, Some(5))]).is_none());
+
+    // Forward-referencing parent index:
+    assert!(
+        Tree::<DefaultKey, usize>::from_parented([(0, None), (1, Some(2)), (2, Some(0))]).is_none()
+    );
+}
+
+#[test]
+fn test_from_parented_with_depth_2_tree() {
+    let tree = Tree::<DefaultKey, usize>::from_parented([
+        (0, None),
+        (1, Some(0)),
+        (2, Some(0)),
+        (3, Some(0)),
+    ])
+    .unwrap();
+
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_from_parented_with_medium_tree() {
+    let records = (0..40).map(|value| match value {
+        0 => (value, None),
+        _ => (value, Some((value - 1) / 3)),
+    });
+
+    let tree = Tree::<DefaultKey, usize>::from_parented(records).unwrap();
+
+    assert_eq!(tree.len(), 40);
+    assert!(tree.structurally_eq(&medium()));
+}
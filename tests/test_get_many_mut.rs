@@ -0,0 +1,61 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_get_many_mut_with_non_existent_keys() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.get_many_mut([DefaultKey::default()]).is_none());
+
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree
+        .get_many_mut([root_key, DefaultKey::default()])
+        .is_none());
+}
+
+#[test]
+fn test_get_many_mut_with_duplicate_keys() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.get_many_mut([root_key, root_key]).is_none());
+}
+
+#[test]
+fn test_get_many_mut_with_no_keys() {
+    let mut tree = depth_2_tree();
+
+    assert!(tree.get_many_mut([]).is_some());
+}
+
+#[test]
+fn test_get_many_mut_with_medium_tree() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key_1 = tree.get(root_key).unwrap().child_keys[0];
+    let child_key_2 = tree.get(root_key).unwrap().child_keys[2];
+
+    let [node_1, node_2, root_node] = tree
+        .get_many_mut([child_key_1, child_key_2, root_key])
+        .unwrap();
+
+    assert_eq!(node_1.parent_key, Some(root_key));
+    assert_eq!(node_2.parent_key, Some(root_key));
+    assert_eq!(root_node.child_keys.len(), 3);
+
+    std::mem::swap(node_1.value, node_2.value);
+    *root_node.value += *node_1.value + *node_2.value;
+
+    assert_eq!(*tree.get(child_key_1).unwrap().value, 3);
+    assert_eq!(*tree.get(child_key_2).unwrap().value, 1);
+    assert_eq!(*tree.get(root_key).unwrap().value, 4);
+}
@@ -0,0 +1,78 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn preorder_values(tree: &Tree<DefaultKey, usize>) -> Vec<usize> {
+    tree.preorder(tree.root_key().unwrap())
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect()
+}
+
+#[test]
+fn test_graft_with_non_existent_parent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(!tree.graft(depth_2_tree(), DefaultKey::default()));
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_graft_with_empty_other_tree() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.graft(Tree::default(), root_key));
+    assert_eq!(preorder_values(&tree), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_graft_with_depth_2_tree() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = tree.get(root_key).unwrap().child_keys[0];
+
+    assert!(tree.graft(linear_depth_4_tree(), leaf_key));
+
+    assert_eq!(tree.len(), 8);
+    assert_eq!(preorder_values(&tree), vec![0, 1, 0, 1, 2, 3, 2, 3]);
+
+    let grafted_root_key = tree.get(leaf_key).unwrap().child_keys[0];
+    assert_eq!(
+        tree.get(grafted_root_key).unwrap().parent_key,
+        Some(leaf_key)
+    );
+}
+
+#[test]
+fn test_graft_appends_as_last_child() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.graft(depth_2_tree(), root_key));
+
+    assert_eq!(tree.get(root_key).unwrap().child_keys.len(), 4);
+    assert_eq!(preorder_values(&tree), vec![0, 1, 2, 3, 0, 1, 2, 3]);
+}
+
+#[test]
+fn test_graft_inverts_detach_subtree() {
+    let mut tree = medium();
+    let original_preorder_values = preorder_values(&tree);
+    let root_key = tree.root_key().unwrap();
+    let last_child_key = tree.get(root_key).unwrap().child_keys[2];
+
+    let detached_tree = tree.detach_subtree(last_child_key).unwrap();
+    assert_eq!(tree.len(), 27);
+
+    assert!(tree.graft(detached_tree, root_key));
+    assert_eq!(tree.len(), 40);
+    assert_eq!(preorder_values(&tree), original_preorder_values);
+}
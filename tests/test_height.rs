@@ -0,0 +1,50 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    linear_depth_4_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_height_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.height(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_height_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    assert_eq!(tree.height(tree.root_key().unwrap()), Some(0));
+}
+
+#[test]
+fn test_height_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    assert_eq!(tree.height(root_key), Some(3));
+    assert_eq!(tree.height(child_key), Some(2));
+}
+
+#[test]
+fn test_height_with_large_tree() {
+    let tree = large();
+
+    for (key, node) in tree.iter() {
+        let expected_height = match *node.value {
+            0 => 3,
+            1..=4 => 2,
+            5..=20 => 1,
+            _ => 0,
+        };
+
+        assert_eq!(tree.height(key), Some(expected_height));
+    }
+}
@@ -0,0 +1,50 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_index_with_medium_tree() {
+    let tree = medium();
+
+    for (key, node) in tree.iter() {
+        assert_eq!(tree[key], *node.value);
+    }
+}
+
+#[test]
+fn test_index_mut_with_depth_2_tree() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+
+    tree[child_key] += 10;
+    tree[root_key] = 100;
+
+    assert_eq!(tree[child_key], 12);
+    assert_eq!(*tree.get(root_key).unwrap().value, 100);
+}
+
+#[test]
+#[should_panic]
+fn test_index_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    let _ = tree[DefaultKey::default()];
+}
+
+#[test]
+#[should_panic]
+fn test_index_mut_with_removed_key() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    tree.remove(child_key, None);
+
+    tree[child_key] = 1;
+}
@@ -0,0 +1,89 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_insert_before_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.insert_before(1, 'b', 0));
+    assert!(!declarative_tree.insert_after(1, 'b', 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_insert_before_with_root_or_non_existent_key() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.insert_before(2, 'c', 0));
+    assert!(!declarative_tree.insert_after(2, 'c', 0));
+    assert!(!declarative_tree.insert_before(2, 'c', 100));
+    assert!(!declarative_tree.insert_after(2, 'c', 100));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_insert_before() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.insert_before(4, 'e', 1));
+    assert!(declarative_tree.insert_before(5, 'f', 2));
+    assert!(declarative_tree.insert_before(6, 'g', 3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 4, 'e', [] },
+        node! { 1, 'b', [
+            node! { 6, 'g', [] },
+            node! { 3, 'd', [] },
+        ] },
+        node! { 5, 'f', [] },
+        node! { 2, 'c', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_insert_after() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.insert_after(4, 'e', 1));
+    assert!(declarative_tree.insert_after(5, 'f', 2));
+    assert!(declarative_tree.insert_after(6, 'g', 3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 6, 'g', [] },
+        ] },
+        node! { 4, 'e', [] },
+        node! { 2, 'c', [] },
+        node! { 5, 'f', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
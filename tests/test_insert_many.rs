@@ -0,0 +1,72 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_insert_many_with_non_existent_parent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.insert_many(DefaultKey::default(), [1, 2, 3]).is_none());
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_insert_many_with_no_values() {
+    let mut tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.insert_many(root_key, []), Some(vec![]));
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn test_insert_many_with_single_root_tree() {
+    let mut tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let keys = tree.insert_many(root_key, 1..4).unwrap();
+
+    assert_eq!(keys.len(), 3);
+    assert_eq!(
+        tree.get(root_key)
+            .unwrap()
+            .child_keys
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        keys,
+    );
+    assert!(keys
+        .iter()
+        .all(|&key| tree.get(key).unwrap().parent_key == Some(root_key)));
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_insert_many_appends_after_existing_children() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = tree.get(root_key).unwrap().child_keys[0];
+
+    tree.insert_many(root_key, vec![4, 5]).unwrap();
+    tree.insert_many(leaf_key, (6..9).filter(|value| value % 2 == 0))
+        .unwrap();
+
+    let mut expected_tree = Tree::<DefaultKey, usize>::default();
+    let expected_root_key = expected_tree.insert_root(0);
+    let expected_leaf_key = expected_tree.insert(1, expected_root_key).unwrap();
+    for value in [2, 3, 4, 5] {
+        expected_tree.insert(value, expected_root_key).unwrap();
+    }
+    for value in [6, 8] {
+        expected_tree.insert(value, expected_leaf_key).unwrap();
+    }
+
+    assert!(tree.structurally_eq(&expected_tree));
+}
@@ -0,0 +1,91 @@
+use std::rc::Rc;
+
+use slotmap::DefaultKey;
+use tinytree::{
+    KeyBuildHasher,
+    Tree,
+};
+
+#[test]
+fn test_intern_with_empty_tree() {
+    let tree = Tree::<DefaultKey, String>::default();
+
+    let (interned_tree, unique_count) = tree.intern();
+
+    assert!(interned_tree.is_empty());
+    assert_eq!(unique_count, 0);
+}
+
+#[test]
+fn test_intern_shares_equal_values() {
+    let mut tree = Tree::<DefaultKey, String>::default();
+
+    let root_key = tree.insert_root("a".into());
+    let child_key_1 = tree.insert("b".into(), root_key).unwrap();
+    tree.insert("a".into(), child_key_1).unwrap();
+    tree.insert("b".into(), root_key).unwrap();
+    tree.insert("c".into(), root_key).unwrap();
+
+    let (interned_tree, unique_count) = tree.intern();
+
+    assert_eq!(interned_tree.len(), 5);
+    assert_eq!(unique_count, 3);
+
+    let (interned_root_key, interned_root_node) = interned_tree.root_key_value().unwrap();
+    assert_eq!(interned_root_node.value.as_str(), "a");
+
+    let interned_child_keys = interned_tree
+        .get(interned_root_key)
+        .unwrap()
+        .child_keys
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(interned_child_keys.len(), 3);
+
+    let interned_child_node_1 = interned_tree.get(interned_child_keys[0]).unwrap();
+    let interned_child_node_2 = interned_tree.get(interned_child_keys[1]).unwrap();
+    let interned_child_node_3 = interned_tree.get(interned_child_keys[2]).unwrap();
+    let interned_grandchild_node = interned_tree
+        .get(interned_child_node_1.child_keys[0])
+        .unwrap();
+
+    assert_eq!(interned_child_node_1.value.as_str(), "b");
+    assert_eq!(interned_child_node_3.value.as_str(), "c");
+    assert!(Rc::ptr_eq(
+        interned_child_node_1.value,
+        interned_child_node_2.value
+    ));
+    assert!(Rc::ptr_eq(
+        interned_root_node.value,
+        interned_grandchild_node.value
+    ));
+    assert!(!Rc::ptr_eq(
+        interned_root_node.value,
+        interned_child_node_1.value
+    ));
+}
+
+#[test]
+fn test_intern_with_hasher() {
+    let mut tree = Tree::<DefaultKey, String>::default();
+
+    let root_key = tree.insert_root("a".into());
+    tree.insert("a".into(), root_key).unwrap();
+    tree.insert("b".into(), root_key).unwrap();
+
+    let (interned_tree, unique_count) = tree.intern_with_hasher(KeyBuildHasher::default());
+
+    assert_eq!(interned_tree.len(), 3);
+    assert_eq!(unique_count, 2);
+
+    let (interned_root_key, interned_root_node) = interned_tree.root_key_value().unwrap();
+    let interned_child_node = interned_tree
+        .get(interned_tree.get(interned_root_key).unwrap().child_keys[0])
+        .unwrap();
+
+    assert!(Rc::ptr_eq(
+        interned_root_node.value,
+        interned_child_node.value
+    ));
+}
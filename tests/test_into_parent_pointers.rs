@@ -0,0 +1,113 @@
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_into_parent_pointers_with_empty_tree() {
+    let tree = Tree::<DefaultKey, char>::default();
+
+    let (parent_pointers, key_map) = tree.into_parent_pointers();
+    assert!(parent_pointers.is_empty());
+    assert!(key_map.is_empty());
+}
+
+#[test]
+fn test_into_parent_pointers_round_trip() {
+    let mut tree = Tree::<DefaultKey, char>::default();
+
+    let root_key = tree.insert_root('a');
+    let child_key_1 = tree.insert('b', root_key).unwrap();
+    let child_key_2 = tree.insert('c', root_key).unwrap();
+    let grandchild_key_1 = tree.insert('d', child_key_1).unwrap();
+    let grandchild_key_2 = tree.insert('e', child_key_1).unwrap();
+
+    let (parent_pointers, key_map) = tree.clone().into_parent_pointers();
+
+    assert_eq!(parent_pointers.len(), 5);
+    assert!(key_map.iter().all(|(old_key, &key)| old_key == key));
+    assert_eq!(*parent_pointers.get(root_key).unwrap(), (None, 'a'));
+    assert_eq!(
+        *parent_pointers.get(child_key_1).unwrap(),
+        (Some(root_key), 'b')
+    );
+    assert_eq!(
+        *parent_pointers.get(grandchild_key_2).unwrap(),
+        (Some(child_key_1), 'e')
+    );
+
+    let (round_tripped_tree, _) = Tree::from_parent_pointers(parent_pointers).unwrap();
+
+    assert_eq!(round_tripped_tree.len(), tree.len());
+    assert_eq!(round_tripped_tree.root_key(), tree.root_key());
+
+    for key in [
+        root_key,
+        child_key_1,
+        child_key_2,
+        grandchild_key_1,
+        grandchild_key_2,
+    ] {
+        let expected_node = tree.get(key).unwrap();
+        let actual_node = round_tripped_tree.get(key).unwrap();
+
+        assert_eq!(actual_node.parent_key, expected_node.parent_key);
+        assert_eq!(actual_node.child_keys, expected_node.child_keys);
+        assert_eq!(actual_node.value, expected_node.value);
+    }
+}
+
+#[test]
+fn test_into_parent_pointers_with_removal() {
+    let mut tree = Tree::<DefaultKey, char>::default();
+
+    let root_key = tree.insert_root('a');
+    let removed_key = tree.insert('z', root_key).unwrap();
+    tree.remove(removed_key, None).unwrap();
+    let child_key = tree.insert('b', root_key).unwrap();
+    let grandchild_key = tree.insert('c', child_key).unwrap();
+
+    let (parent_pointers, key_map) = tree.clone().into_parent_pointers();
+
+    assert_eq!(parent_pointers.len(), 3);
+    assert_eq!(key_map.len(), 3);
+    assert!(!key_map.contains_key(removed_key));
+    assert_ne!(key_map[child_key], child_key);
+
+    let key_of = |old_key| key_map[old_key];
+
+    assert_eq!(parent_pointers[key_of(root_key)], (None, 'a'));
+    assert_eq!(
+        parent_pointers[key_of(child_key)],
+        (Some(key_of(root_key)), 'b'),
+    );
+    assert_eq!(
+        parent_pointers[key_of(grandchild_key)],
+        (Some(key_of(child_key)), 'c'),
+    );
+
+    let (round_tripped_tree, _) = Tree::from_parent_pointers(parent_pointers).unwrap();
+
+    assert!(round_tripped_tree.structurally_eq(&tree));
+}
+
+#[test]
+fn test_into_parent_pointers_round_trip_with_reordered_children() {
+    let mut tree = Tree::<DefaultKey, char>::default();
+
+    let root_key = tree.insert_root('a');
+    let child_key_1 = tree.insert('b', root_key).unwrap();
+    let child_key_2 = tree.insert('c', root_key).unwrap();
+    let grandchild_key_1 = tree.insert('d', child_key_1).unwrap();
+    tree.insert('e', child_key_1).unwrap();
+    let grandchild_key_3 = tree.insert('f', child_key_2).unwrap();
+
+    assert!(tree.move_child_to_front(child_key_2));
+    assert!(tree.move_child_to_back(grandchild_key_1));
+    assert!(tree.rebase(grandchild_key_3, child_key_1));
+    assert!(tree.move_child_to_front(grandchild_key_3));
+
+    let (parent_pointers, key_map) = tree.clone().into_parent_pointers();
+    let (round_tripped_tree, _) = Tree::from_parent_pointers(parent_pointers).unwrap();
+
+    assert_eq!(key_map.len(), tree.len());
+    assert!(round_tripped_tree.structurally_eq(&tree));
+}
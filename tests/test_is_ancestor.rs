@@ -0,0 +1,71 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_is_ancestor_with_non_existent_keys() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .is_ancestor(DefaultKey::default(), DefaultKey::default())
+        .is_none());
+    assert!(tree
+        .is_descendant(DefaultKey::default(), DefaultKey::default())
+        .is_none());
+
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.is_ancestor(root_key, DefaultKey::default()).is_none());
+    assert!(tree
+        .is_descendant(DefaultKey::default(), root_key)
+        .is_none());
+}
+
+#[test]
+fn test_is_ancestor_with_same_key() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.is_ancestor(root_key, root_key), Some(false));
+    assert_eq!(tree.is_descendant(root_key, root_key), Some(false));
+}
+
+#[test]
+fn test_is_ancestor_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert_eq!(tree.is_ancestor(root_key, leaf_key), Some(true));
+    assert_eq!(tree.is_ancestor(child_key, leaf_key), Some(true));
+    assert_eq!(tree.is_ancestor(leaf_key, root_key), Some(false));
+
+    assert_eq!(tree.is_descendant(leaf_key, root_key), Some(true));
+    assert_eq!(tree.is_descendant(leaf_key, child_key), Some(true));
+    assert_eq!(tree.is_descendant(root_key, leaf_key), Some(false));
+}
+
+#[test]
+fn test_is_ancestor_with_siblings() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = &tree.get(root_key).unwrap().child_keys;
+    let child_key_1 = child_keys[0];
+    let child_key_2 = child_keys[1];
+    let grandchild_key = tree.get(child_key_2).unwrap().child_keys[0];
+
+    assert_eq!(tree.is_ancestor(child_key_1, child_key_2), Some(false));
+    assert_eq!(tree.is_ancestor(child_key_1, grandchild_key), Some(false));
+    assert_eq!(tree.is_descendant(grandchild_key, child_key_1), Some(false));
+
+    assert_eq!(tree.is_ancestor(child_key_2, grandchild_key), Some(true));
+    assert_eq!(tree.is_descendant(grandchild_key, child_key_2), Some(true));
+}
@@ -0,0 +1,40 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_is_leaf_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.is_leaf(DefaultKey::default()).is_none());
+    assert!(tree.is_root(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_is_leaf_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.is_leaf(root_key), Some(true));
+    assert_eq!(tree.is_root(root_key), Some(true));
+}
+
+#[test]
+fn test_is_leaf_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.is_leaf(root_key), Some(false));
+    assert_eq!(tree.is_root(root_key), Some(true));
+
+    for &child_key in tree.get(root_key).unwrap().child_keys {
+        assert_eq!(tree.is_leaf(child_key), Some(true));
+        assert_eq!(tree.is_root(child_key), Some(false));
+    }
+}
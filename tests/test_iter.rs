@@ -0,0 +1,64 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_iter_lengths_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.keys().len(), 0);
+    assert_eq!(tree.nodes().len(), 0);
+    assert_eq!(tree.nodes_mut().len(), 0);
+    assert_eq!(tree.values().len(), 0);
+    assert_eq!(tree.values_mut().len(), 0);
+    assert_eq!(tree.iter().len(), 0);
+    assert_eq!(tree.iter_mut().len(), 0);
+}
+
+#[test]
+fn test_iter_lengths_with_medium_tree() {
+    let mut tree = medium();
+
+    assert_eq!(tree.keys().len(), 40);
+    assert_eq!(tree.nodes().len(), 40);
+    assert_eq!(tree.nodes_mut().len(), 40);
+    assert_eq!(tree.values().len(), 40);
+    assert_eq!(tree.values_mut().len(), 40);
+    assert_eq!(tree.iter().len(), 40);
+    assert_eq!(tree.iter_mut().len(), 40);
+}
+
+#[test]
+fn test_iter_lengths_shrink_while_iterating() {
+    let tree = medium();
+    let mut iter = tree.iter();
+
+    for remaining in (0..40).rev() {
+        assert!(iter.next().is_some());
+        assert_eq!(iter.len(), remaining);
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+    }
+
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_iter_yields_every_key_and_value() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let mut values = tree.iter().map(|(_, node)| *node.value).collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, (0..40).collect::<Vec<_>>());
+
+    for (key, node) in tree.iter_mut() {
+        assert_eq!(key == root_key, node.parent_key.is_none());
+        *node.value += 1;
+    }
+
+    assert!(tree.structurally_eq(&medium().map(|value| value + 1)));
+}
@@ -0,0 +1,43 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_key_of_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.key_of(&0).is_none());
+    assert!(!tree.contains_value(&0));
+}
+
+#[test]
+fn test_key_of_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.key_of(&0), Some(root_key));
+    assert!(tree.contains_value(&0));
+
+    for (key, node) in tree.iter() {
+        assert_eq!(tree.key_of(node.value), Some(key));
+        assert!(tree.contains_value(node.value));
+    }
+
+    assert!(tree.key_of(&40).is_none());
+    assert!(!tree.contains_value(&40));
+}
+
+#[test]
+fn test_key_of_with_duplicate_values() {
+    let mut tree = Tree::<DefaultKey, &str>::default();
+    let root_key = tree.insert_root("a");
+    let child_key_1 = tree.insert("b", root_key).unwrap();
+    let child_key_2 = tree.insert("b", root_key).unwrap();
+
+    let key = tree.key_of(&"b").unwrap();
+
+    assert!(key == child_key_1 || key == child_key_2);
+}
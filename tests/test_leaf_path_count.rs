@@ -0,0 +1,48 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_leaf_path_count_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.leaf_path_count(), 0);
+    assert!(tree.leaf_path_count_under(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_leaf_path_count_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.leaf_path_count(), 1);
+    assert_eq!(tree.leaf_path_count_under(root_key), Some(1));
+}
+
+#[test]
+fn test_leaf_path_count_with_medium_tree() {
+    let tree = medium();
+
+    assert_eq!(tree.leaf_path_count(), 27);
+}
+
+#[test]
+fn test_leaf_path_count_under_subtree_of_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    assert_eq!(tree.leaf_path_count_under(child_key), Some(9));
+
+    let grandchild_key = tree.get(child_key).unwrap().child_keys[0];
+    assert_eq!(tree.leaf_path_count_under(grandchild_key), Some(3));
+
+    let leaf_key = tree.get(grandchild_key).unwrap().child_keys[0];
+    assert_eq!(tree.leaf_path_count_under(leaf_key), Some(1));
+}
@@ -0,0 +1,38 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_leaves_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.leaves().count(), 0);
+}
+
+#[test]
+fn test_leaves_with_single_root_tree() {
+    let tree = single_root_tree();
+
+    let leaf_keys = tree.leaves().map(|(key, _)| key).collect::<Vec<_>>();
+
+    assert_eq!(leaf_keys, vec![tree.root_key().unwrap()]);
+}
+
+#[test]
+fn test_leaves_with_medium_tree() {
+    let tree = medium();
+
+    let mut leaf_values = tree
+        .leaves()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+    leaf_values.sort();
+
+    assert_eq!(leaf_values, (13..40).collect::<Vec<_>>());
+}
@@ -0,0 +1,54 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_level_values_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.level_values().is_empty());
+}
+
+#[test]
+fn test_level_values_with_depth_2_tree() {
+    let tree = depth_2_tree();
+
+    assert_eq!(tree.level_values(), vec![vec![&0], vec![&1, &2, &3]]);
+}
+
+#[test]
+fn test_level_values_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+
+    assert_eq!(
+        tree.level_values(),
+        vec![vec![&0], vec![&1], vec![&2], vec![&3]],
+    );
+}
+
+#[test]
+fn test_level_values_with_medium_tree() {
+    let tree = medium();
+
+    let level_values = tree.level_values();
+
+    assert_eq!(level_values.len(), 4);
+    assert_eq!(
+        level_values
+            .iter()
+            .map(|values| values.len())
+            .collect::<Vec<_>>(),
+        vec![1, 3, 9, 27],
+    );
+    assert_eq!(
+        level_values[3].iter().copied().copied().collect::<Vec<_>>(),
+        (13..40).collect::<Vec<_>>(),
+    );
+}
@@ -0,0 +1,51 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_levels_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.levels().is_empty());
+}
+
+#[test]
+fn test_levels_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree
+        .get(root_key)
+        .unwrap()
+        .child_keys
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+
+    assert_eq!(tree.levels(), vec![vec![root_key], child_keys]);
+}
+
+#[test]
+fn test_levels_with_medium_tree() {
+    let tree = medium();
+
+    let levels = tree.levels();
+
+    assert_eq!(
+        levels.iter().map(|keys| keys.len()).collect::<Vec<_>>(),
+        vec![1, 3, 9, 27],
+    );
+    assert_eq!(
+        levels
+            .iter()
+            .flatten()
+            .map(|&key| tree[key])
+            .collect::<Vec<_>>(),
+        (0..40).collect::<Vec<_>>(),
+    );
+}
@@ -0,0 +1,55 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_map_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.map(|value| value.to_string()).is_empty());
+    assert!(tree.map_with_key(|key, _| key).is_empty());
+}
+
+#[test]
+fn test_map_with_depth_2_tree() {
+    let tree = depth_2_tree();
+
+    let mapped_tree = tree.map(|value| value.to_string());
+
+    let mut expected_tree = Tree::<DefaultKey, String>::default();
+    let root_key = expected_tree.insert_root("0".into());
+    expected_tree.insert("1".into(), root_key).unwrap();
+    expected_tree.insert("2".into(), root_key).unwrap();
+    expected_tree.insert("3".into(), root_key).unwrap();
+
+    assert!(mapped_tree.structurally_eq(&expected_tree));
+}
+
+#[test]
+fn test_map_with_medium_tree() {
+    let tree = medium();
+
+    let mapped_tree = tree.map(|value| value * 2);
+
+    assert_eq!(mapped_tree.len(), tree.len());
+    assert!(mapped_tree.structurally_eq(&medium().map(|&value| value + value)));
+    assert!(!mapped_tree.structurally_eq(&tree));
+}
+
+#[test]
+fn test_map_with_key_with_medium_tree() {
+    let tree = medium();
+
+    let key_tree = tree.map_with_key(|key, _| key);
+
+    assert_eq!(key_tree.len(), tree.len());
+    assert!(key_tree
+        .map(|&old_key| *tree.get(old_key).unwrap().value)
+        .structurally_eq(&tree));
+}
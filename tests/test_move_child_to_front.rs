@@ -0,0 +1,91 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_move_child_to_front_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.move_child_to_front(0));
+    assert!(!declarative_tree.move_child_to_back(0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_move_child_to_front_with_root_or_non_existent_key() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.move_child_to_front(0));
+    assert!(!declarative_tree.move_child_to_back(0));
+    assert!(!declarative_tree.move_child_to_front(100));
+    assert!(!declarative_tree.move_child_to_back(100));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_move_child_to_front() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] }));
+
+    assert!(declarative_tree.move_child_to_front(3));
+    assert!(declarative_tree.move_child_to_front(5));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 3, 'd', [] },
+        node! { 1, 'b', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 4, 'e', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_move_child_to_back() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] }));
+
+    assert!(declarative_tree.move_child_to_back(1));
+    assert!(declarative_tree.move_child_to_back(3));
+    assert!(declarative_tree.move_child_to_back(5));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'c', [] },
+        node! { 4, 'e', [] },
+        node! { 1, 'b', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 3, 'd', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
@@ -0,0 +1,44 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_node_and_parent_mut_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.node_and_parent_mut(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_node_and_parent_mut_with_root_key() {
+    let mut tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.node_and_parent_mut(root_key).is_none());
+}
+
+#[test]
+fn test_node_and_parent_mut_with_medium_tree() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+    let grandchild_key = tree.get(child_key).unwrap().child_keys[0];
+
+    let (node, parent_node) = tree.node_and_parent_mut(grandchild_key).unwrap();
+
+    assert_eq!(node.parent_key, Some(child_key));
+    assert_eq!(parent_node.parent_key, Some(root_key));
+    assert!(parent_node.child_keys.contains(&grandchild_key));
+
+    *parent_node.value += *node.value;
+    *node.value = 0;
+
+    assert_eq!(tree[child_key], 2 + 7);
+    assert_eq!(tree[grandchild_key], 0);
+}
@@ -0,0 +1,60 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_node_at_path_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.node_at_path(&[]).is_none());
+    assert!(tree.node_at_path(&[0]).is_none());
+}
+
+#[test]
+fn test_node_at_path_with_empty_path() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let (key, node) = tree.node_at_path(&[]).unwrap();
+
+    assert_eq!(key, root_key);
+    assert_eq!(*node.value, 0);
+}
+
+#[test]
+fn test_node_at_path_with_depth_2_tree() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys;
+
+    for (index, &child_key) in child_keys.iter().enumerate() {
+        let (key, node) = tree.node_at_path(&[index]).unwrap();
+
+        assert_eq!(key, child_key);
+        assert_eq!(node.parent_key, Some(root_key));
+    }
+
+    assert!(tree.node_at_path(&[3]).is_none());
+    assert!(tree.node_at_path(&[0, 0]).is_none());
+}
+
+#[test]
+fn test_node_at_path_with_medium_tree() {
+    let tree = medium();
+    let value_at = |indices: &[usize]| tree.node_at_path(indices).map(|(_, node)| *node.value);
+
+    assert_eq!(value_at(&[0]), Some(1));
+    assert_eq!(value_at(&[2]), Some(3));
+    assert_eq!(value_at(&[0, 0]), Some(4));
+    assert_eq!(value_at(&[1, 2]), Some(9));
+    assert_eq!(value_at(&[0, 0, 0]), Some(13));
+    assert_eq!(value_at(&[2, 2, 2]), Some(39));
+    assert_eq!(value_at(&[2, 2, 3]), None);
+    assert_eq!(value_at(&[2, 2, 2, 0]), None);
+}
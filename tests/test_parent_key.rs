@@ -0,0 +1,35 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_parent_key_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.parent_key(DefaultKey::default()), None);
+}
+
+#[test]
+fn test_parent_key_with_root_key() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.parent_key(root_key), Some(None));
+}
+
+#[test]
+fn test_parent_key_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    for (key, node) in tree.iter() {
+        assert_eq!(tree.parent_key(key), Some(node.parent_key));
+    }
+
+    for &child_key in tree.get(root_key).unwrap().child_keys {
+        assert_eq!(tree.parent_key(child_key), Some(Some(root_key)));
+    }
+}
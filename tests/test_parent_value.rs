@@ -0,0 +1,49 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_parent_value_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.parent_value(DefaultKey::default()).is_none());
+    assert!(tree.parent_value_mut(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_parent_value_with_root_key() {
+    let mut tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.parent_value(root_key).is_none());
+    assert!(tree.parent_value_mut(root_key).is_none());
+}
+
+#[test]
+fn test_parent_value_with_medium_tree() {
+    let tree = medium();
+
+    for value in 1..40 {
+        let key = tree.key_of(&value).unwrap();
+
+        assert_eq!(tree.parent_value(key), Some(&((value - 1) / 3)));
+    }
+}
+
+#[test]
+fn test_parent_value_mut_with_medium_tree() {
+    let mut tree = medium();
+    let key = tree.key_of(&13).unwrap();
+
+    *tree.parent_value_mut(key).unwrap() = 100;
+
+    assert_eq!(tree.parent_value(key), Some(&100));
+    assert_eq!(tree[tree.key_of(&100).unwrap()], 100);
+    assert!(!tree.contains_value(&4));
+}
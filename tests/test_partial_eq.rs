@@ -0,0 +1,77 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_partial_eq_with_empty_trees() {
+    assert_eq!(
+        Tree::<DefaultKey, usize>::default(),
+        Tree::<DefaultKey, usize>::default(),
+    );
+    assert_ne!(Tree::<DefaultKey, usize>::default(), depth_2_tree());
+}
+
+#[test]
+fn test_partial_eq_with_clone() {
+    let tree = medium();
+
+    assert_eq!(tree, tree.clone());
+}
+
+#[test]
+fn test_partial_eq_with_diverging_values() {
+    let tree = medium();
+    let mut other_tree = tree.clone();
+    let (key, _) = other_tree.find(|&value| value == 20).unwrap();
+    other_tree[key] = 200;
+
+    assert_ne!(tree, other_tree);
+
+    other_tree[key] = 20;
+
+    assert_eq!(tree, other_tree);
+}
+
+#[test]
+fn test_partial_eq_with_diverging_child_order() {
+    let tree = depth_2_tree();
+    let mut other_tree = tree.clone();
+    let root_key = other_tree.root_key().unwrap();
+    let child_keys = other_tree.get(root_key).unwrap().child_keys.clone();
+
+    assert!(other_tree.swap_node_positions(child_keys[0], child_keys[2]));
+
+    assert_ne!(tree, other_tree);
+}
+
+#[test]
+fn test_partial_eq_with_diverging_keys() {
+    let tree = depth_2_tree();
+    let mut other_tree = tree.clone();
+    let root_key = other_tree.root_key().unwrap();
+    let child_key = other_tree.get(root_key).unwrap().child_keys[2];
+
+    let value = other_tree.remove(child_key, None).unwrap();
+    other_tree.insert(value, root_key).unwrap();
+
+    assert!(tree.structurally_eq(&other_tree));
+    assert_ne!(tree, other_tree);
+}
+
+#[test]
+fn test_partial_eq_with_diverging_parents() {
+    let tree = depth_2_tree();
+    let mut other_tree = tree.clone();
+    let root_key = other_tree.root_key().unwrap();
+    let child_keys = other_tree.get(root_key).unwrap().child_keys.clone();
+
+    assert!(other_tree.rebase(child_keys[2], child_keys[1]));
+
+    assert_ne!(tree, other_tree);
+}
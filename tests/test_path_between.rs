@@ -0,0 +1,76 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn values(tree: &Tree<DefaultKey, usize>, keys: &[DefaultKey]) -> Vec<usize> {
+    keys.iter()
+        .map(|&key| *tree.get(key).unwrap().value)
+        .collect()
+}
+
+fn find_key(tree: &Tree<DefaultKey, usize>, value: usize) -> DefaultKey {
+    tree.iter()
+        .find(|(_, node)| *node.value == value)
+        .unwrap()
+        .0
+}
+
+#[test]
+fn test_path_between_with_non_existent_keys() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .path_between(DefaultKey::default(), DefaultKey::default())
+        .is_none());
+
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.path_between(root_key, DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_path_between_with_same_key() {
+    let tree = medium();
+    let key = find_key(&tree, 5);
+
+    assert_eq!(tree.path_between(key, key), Some((vec![key], vec![key])));
+}
+
+#[test]
+fn test_path_between_with_ancestral_keys() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = find_key(&tree, 3);
+
+    let (path_1, path_2) = tree.path_between(leaf_key, root_key).unwrap();
+    assert_eq!(values(&tree, &path_1), vec![3, 2, 1, 0]);
+    assert_eq!(values(&tree, &path_2), vec![0]);
+
+    let (path_1, path_2) = tree.path_between(root_key, leaf_key).unwrap();
+    assert_eq!(values(&tree, &path_1), vec![0]);
+    assert_eq!(values(&tree, &path_2), vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_path_between_with_siblings() {
+    let tree = medium();
+
+    let (path_1, path_2) = tree
+        .path_between(find_key(&tree, 13), find_key(&tree, 17))
+        .unwrap();
+    assert_eq!(values(&tree, &path_1), vec![13, 4, 1]);
+    assert_eq!(values(&tree, &path_2), vec![17, 5, 1]);
+
+    let (path_1, path_2) = tree
+        .path_between(find_key(&tree, 39), find_key(&tree, 2))
+        .unwrap();
+    assert_eq!(values(&tree, &path_1), vec![39, 12, 3, 0]);
+    assert_eq!(values(&tree, &path_2), vec![2, 0]);
+}
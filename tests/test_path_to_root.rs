@@ -0,0 +1,56 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn values(tree: &Tree<DefaultKey, usize>, keys: &[DefaultKey]) -> Vec<usize> {
+    keys.iter()
+        .map(|&key| *tree.get(key).unwrap().value)
+        .collect()
+}
+
+#[test]
+fn test_path_to_root_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.path_to_root(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_path_to_root_with_root_key() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.path_to_root(root_key), Some(vec![root_key]));
+}
+
+#[test]
+fn test_path_to_root_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let (leaf_key, _) = tree.leaves().next().unwrap();
+
+    let path = tree.path_to_root(leaf_key).unwrap();
+
+    assert_eq!(values(&tree, &path), vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_path_to_root_with_medium_tree() {
+    let tree = medium();
+
+    for (key, node) in tree.leaves() {
+        let path = tree.path_to_root(key).unwrap();
+        let parent_value = (node.value - 1) / 3;
+        let grandparent_value = (parent_value - 1) / 3;
+
+        assert_eq!(
+            values(&tree, &path),
+            vec![*node.value, parent_value, grandparent_value, 0],
+        );
+    }
+}
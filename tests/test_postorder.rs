@@ -0,0 +1,66 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_postorder_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.postorder(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_postorder_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let values = tree
+        .postorder(root_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_postorder_with_subtree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    let values = tree
+        .postorder(child_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![13, 14, 15, 4, 16, 17, 18, 5, 19, 20, 21, 6, 1],);
+}
+
+#[test]
+fn test_postorder_yields_descendents_before_ancestors() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let keys = tree
+        .postorder(root_key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys.len(), tree.len());
+    assert_eq!(keys.last(), Some(&root_key));
+
+    for (index, &key) in keys.iter().enumerate() {
+        for child_key in tree.get(key).unwrap().child_keys {
+            assert!(keys[..index].contains(child_key));
+        }
+    }
+}
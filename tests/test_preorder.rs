@@ -0,0 +1,65 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_preorder_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.preorder(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_preorder_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let values = tree
+        .preorder(root_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_preorder_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let values = tree
+        .preorder(root_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values.len(), tree.len());
+    assert_eq!(values[..8], [0, 1, 4, 13, 14, 15, 5, 16]);
+    assert_eq!(values[values.len() - 4..], [12, 37, 38, 39]);
+}
+
+#[test]
+fn test_preorder_with_subtree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[2];
+
+    let actual = tree
+        .preorder(child_key)
+        .unwrap()
+        .map(|(key, node)| (key, *node.value))
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual[0], (child_key, 3));
+    assert_eq!(
+        actual.iter().map(|&(_, value)| value).collect::<Vec<_>>(),
+        vec![3, 10, 31, 32, 33, 11, 34, 35, 36, 12, 37, 38, 39],
+    );
+}
@@ -0,0 +1,62 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_preorder_keys_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.preorder_keys(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_preorder_keys_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(
+        tree.preorder_keys(root_key).unwrap().collect::<Vec<_>>(),
+        vec![root_key],
+    );
+}
+
+#[test]
+fn test_preorder_keys_matches_preorder() {
+    let tree = large();
+    let root_key = tree.root_key().unwrap();
+
+    let expected_keys = tree
+        .preorder(root_key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tree.preorder_keys(root_key).unwrap().collect::<Vec<_>>(),
+        expected_keys,
+    );
+}
+
+#[test]
+fn test_preorder_keys_with_medium_subtree() {
+    let tree = medium();
+    let key = tree.key_of(&3).unwrap();
+
+    let values = tree
+        .preorder_keys(key)
+        .unwrap()
+        .map(|key| tree[key])
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        values,
+        vec![3, 10, 31, 32, 33, 11, 34, 35, 36, 12, 37, 38, 39],
+    );
+}
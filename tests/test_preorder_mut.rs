@@ -0,0 +1,79 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_preorder_mut_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.preorder_mut(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_preorder_mut_with_single_root_tree() {
+    let mut tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    for (_, node) in tree.preorder_mut(root_key).unwrap() {
+        *node.value = 100;
+    }
+
+    assert_eq!(tree[root_key], 100);
+}
+
+#[test]
+fn test_preorder_mut_matches_preorder() {
+    let mut tree = medium();
+    let key = tree.key_of(&1).unwrap();
+
+    let expected_keys = tree
+        .preorder(key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+    let actual_keys = tree
+        .preorder_mut(key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(actual_keys, expected_keys);
+}
+
+#[test]
+fn test_preorder_mut_assigns_sequence_numbers() {
+    let mut tree = medium();
+    let key = tree.key_of(&2).unwrap();
+
+    for (sequence_number, (_, node)) in tree.preorder_mut(key).unwrap().enumerate() {
+        *node.value = 100 + sequence_number;
+    }
+
+    let values = tree
+        .preorder(key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, (100..113).collect::<Vec<_>>());
+    assert_eq!(tree[tree.root_key().unwrap()], 0);
+    assert_eq!(tree[tree.key_of(&1).unwrap()], 1);
+}
+
+#[test]
+fn test_preorder_mut_with_leaf() {
+    let mut tree = medium();
+    let key = tree.key_of(&39).unwrap();
+
+    let nodes = tree.preorder_mut(key).unwrap().collect::<Vec<_>>();
+
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].0, key);
+    assert_eq!(*nodes[0].1.value, 39);
+}
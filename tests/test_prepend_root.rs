@@ -0,0 +1,46 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::depth_2_tree;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_prepend_root_into_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.prepend_root(100);
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree.root_key(), Some(root_key));
+
+    let root_node = tree.get(root_key).unwrap();
+    assert_eq!(*root_node.value, 100);
+    assert!(root_node.parent_key.is_none());
+    assert!(root_node.child_keys.is_empty());
+}
+
+#[test]
+fn test_prepend_root_into_depth_2_tree() {
+    let mut tree = depth_2_tree();
+    let old_root_key = tree.root_key().unwrap();
+    let old_length = tree.len();
+
+    let root_key = tree.prepend_root(100);
+
+    assert_eq!(tree.len(), old_length + 1);
+    assert_eq!(tree.root_key(), Some(root_key));
+
+    let root_node = tree.get(root_key).unwrap();
+    assert_eq!(*root_node.value, 100);
+    assert!(root_node.parent_key.is_none());
+    assert_eq!(
+        root_node.child_keys.iter().copied().collect::<Vec<_>>(),
+        vec![old_root_key],
+    );
+
+    let old_root_node = tree.get(old_root_key).unwrap();
+    assert_eq!(*old_root_node.value, 0);
+    assert_eq!(old_root_node.parent_key, Some(root_key));
+    assert_eq!(old_root_node.child_keys.len(), 3);
+}
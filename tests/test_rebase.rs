@@ -136,3 +136,29 @@ fn test_rebase_onto_descendent() {
 
     assert_eq!(actual_declarative_node, expected_declarative_node);
 }
+
+#[test]
+fn test_rebase_onto_descendent_keeps_trailing_siblings_in_order() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase(2, 5));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 5, 'f', [
+            node! { 2, 'c', [] },
+        ] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
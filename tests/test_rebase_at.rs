@@ -0,0 +1,189 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_rebase_at_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.rebase_at(0, 1, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_with_same_or_non_existent_keys() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.rebase_at(1, 1, 0));
+    assert!(!declarative_tree.rebase_at(1, 100, 0));
+    assert!(!declarative_tree.rebase_at(100, 1, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_onto_sibling() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 4, 'e', [] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(2, 1, 1));
+    assert!(declarative_tree.rebase_at(3, 1, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 4, 'e', [] },
+            node! { 2, 'c', [] },
+            node! { 5, 'f', [] },
+        ] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_onto_current_parent_with_out_of_bounds_index() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(1, 0, 100));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+        node! { 1, 'b', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_onto_descendent() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [
+                node! { 5, 'f', [] },
+            ] },
+            node! { 4, 'e', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(1, 3, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 3, 'd', [
+            node! { 1, 'b', [
+                node! { 4, 'e', [] },
+            ] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_onto_deep_descendent_with_non_zero_index() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [
+            node! { 4, 'e', [
+                node! { 6, 'g', [] },
+                node! { 7, 'h', [] },
+            ] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(2, 4, 1));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 4, 'e', [
+            node! { 6, 'g', [] },
+            node! { 2, 'c', [
+                node! { 5, 'f', [] },
+            ] },
+            node! { 7, 'h', [] },
+        ] },
+        node! { 3, 'd', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_onto_descendent_keeps_trailing_siblings_in_order() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [
+            node! { 5, 'f', [] },
+        ] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(2, 5, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 5, 'f', [
+            node! { 2, 'c', [] },
+        ] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_rebase_at_root_onto_descendent() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.rebase_at(0, 1, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 1, 'b', [
+        node! { 0, 'a', [
+            node! { 2, 'c', [] },
+        ] },
+        node! { 3, 'd', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
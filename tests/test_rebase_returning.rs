@@ -0,0 +1,63 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+
+#[test]
+fn test_rebase_returning_with_non_existent_or_same_keys() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let key = tree.key_of(&4).unwrap();
+
+    assert!(tree.rebase_returning(key, DefaultKey::default()).is_none());
+    assert!(tree.rebase_returning(DefaultKey::default(), key).is_none());
+    assert!(tree.rebase_returning(key, key).is_none());
+    assert!(tree.rebase_returning(root_key, root_key).is_none());
+    assert!(tree.structurally_eq(&medium()));
+}
+
+#[test]
+fn test_rebase_returning_onto_sibling() {
+    let mut tree = medium();
+    let key = tree.key_of(&4).unwrap();
+    let old_parent_key = tree.key_of(&1).unwrap();
+    let new_parent_key = tree.key_of(&39).unwrap();
+
+    assert_eq!(
+        tree.rebase_returning(key, new_parent_key),
+        Some(old_parent_key)
+    );
+    assert_eq!(tree.parent_key(key), Some(Some(new_parent_key)));
+
+    assert_eq!(
+        tree.rebase_returning(key, old_parent_key),
+        Some(new_parent_key)
+    );
+    assert_eq!(tree.parent_key(key), Some(Some(old_parent_key)));
+}
+
+#[test]
+fn test_rebase_returning_onto_ancestor() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let key = tree.key_of(&13).unwrap();
+    let old_parent_key = tree.key_of(&4).unwrap();
+
+    assert_eq!(tree.rebase_returning(key, root_key), Some(old_parent_key));
+    assert_eq!(tree.parent_key(key), Some(Some(root_key)));
+}
+
+#[test]
+fn test_rebase_returning_root_onto_descendant() {
+    let mut tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert!(tree.rebase_returning(root_key, leaf_key).is_none());
+    assert_eq!(tree.root_key(), Some(leaf_key));
+    assert_eq!(tree.parent_key(root_key), Some(Some(leaf_key)));
+}
@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_rebuild_complete_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    let complete_tree = tree.rebuild_complete(2);
+    assert!(complete_tree.is_empty());
+    assert!(complete_tree.root_key().is_none());
+}
+
+#[test]
+fn test_rebuild_complete_with_linear_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let mut key = tree.insert_root(0);
+    for value in 1..10 {
+        key = tree.insert(value, key).unwrap();
+    }
+
+    let complete_tree = tree.rebuild_complete(3);
+    assert_eq!(complete_tree.len(), 10);
+
+    let mut values = vec![];
+    let mut child_counts = vec![];
+    let mut to_visit_keys = VecDeque::from_iter(complete_tree.root_key());
+
+    while let Some(key) = to_visit_keys.pop_front() {
+        let node = complete_tree.get(key).unwrap();
+        values.push(*node.value);
+        child_counts.push(node.child_keys.len());
+        to_visit_keys.extend(node.child_keys);
+    }
+
+    assert_eq!(values, (0..10).collect::<Vec<_>>());
+    assert_eq!(child_counts, vec![3, 3, 3, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_rebuild_complete_with_branching_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.insert_root(0);
+    let child_key_1 = tree.insert(1, root_key).unwrap();
+    tree.insert(2, child_key_1).unwrap();
+    tree.insert(3, child_key_1).unwrap();
+    let child_key_4 = tree.insert(4, root_key).unwrap();
+    tree.insert(5, child_key_4).unwrap();
+
+    let complete_tree = tree.rebuild_complete(2);
+    assert_eq!(complete_tree.len(), 6);
+
+    let mut values = vec![];
+    let mut child_counts = vec![];
+    let mut to_visit_keys = VecDeque::from_iter(complete_tree.root_key());
+
+    while let Some(key) = to_visit_keys.pop_front() {
+        let node = complete_tree.get(key).unwrap();
+        values.push(*node.value);
+        child_counts.push(node.child_keys.len());
+        to_visit_keys.extend(node.child_keys);
+    }
+
+    assert_eq!(values, vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(child_counts, vec![2, 2, 1, 0, 0, 0]);
+}
@@ -0,0 +1,51 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_relative_depth_map_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.relative_depth_map(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_relative_depth_map_with_subtree_of_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+
+    let depth_map = tree.relative_depth_map(child_key).unwrap();
+
+    assert_eq!(depth_map.len(), 13);
+    assert_eq!(depth_map[child_key], 0);
+    assert!(!depth_map.contains_key(root_key));
+
+    for (key, &depth) in &depth_map {
+        let expected_depth = match *tree.get(key).unwrap().value {
+            2 => 0,
+            7..=9 => 1,
+            22..=30 => 2,
+            value => panic!("unexpected value {value} in the subtree"),
+        };
+
+        assert_eq!(depth, expected_depth);
+    }
+}
+
+#[test]
+fn test_relative_depth_map_with_leaf() {
+    let tree = medium();
+    let leaf_key = tree
+        .iter()
+        .find_map(|(key, node)| (*node.value == 39).then_some(key))
+        .unwrap();
+
+    let depth_map = tree.relative_depth_map(leaf_key).unwrap();
+
+    assert_eq!(depth_map.len(), 1);
+    assert_eq!(depth_map[leaf_key], 0);
+}
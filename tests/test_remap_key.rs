@@ -0,0 +1,85 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_remap_key_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.remap_key(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_remap_key_with_leaf() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let grandchild_key = tree.get(child_key).unwrap().child_keys[1];
+    let leaf_key = tree.get(grandchild_key).unwrap().child_keys[1];
+
+    let new_leaf_key = tree.remap_key(leaf_key).unwrap();
+
+    assert_ne!(new_leaf_key, leaf_key);
+    assert!(!tree.contains(leaf_key));
+    assert_eq!(tree.len(), 40);
+
+    let leaf_node = tree.get(new_leaf_key).unwrap();
+    assert_eq!(*leaf_node.value, 17);
+    assert_eq!(leaf_node.parent_key, Some(grandchild_key));
+    assert!(leaf_node.child_keys.is_empty());
+
+    let grandchild_node = tree.get(grandchild_key).unwrap();
+    assert_eq!(grandchild_node.child_keys.len(), 3);
+    assert_eq!(grandchild_node.child_keys[1], new_leaf_key);
+}
+
+#[test]
+fn test_remap_key_with_mid_node() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+    let grandchild_keys = tree.get(child_key).unwrap().child_keys.clone();
+
+    let new_child_key = tree.remap_key(child_key).unwrap();
+
+    assert_ne!(new_child_key, child_key);
+    assert!(!tree.contains(child_key));
+
+    let child_node = tree.get(new_child_key).unwrap();
+    assert_eq!(*child_node.value, 2);
+    assert_eq!(child_node.parent_key, Some(root_key));
+    assert_eq!(*child_node.child_keys, grandchild_keys);
+
+    let root_node = tree.get(root_key).unwrap();
+    assert_eq!(root_node.child_keys.len(), 3);
+    assert_eq!(root_node.child_keys[1], new_child_key);
+
+    for grandchild_key in grandchild_keys {
+        let grandchild_node = tree.get(grandchild_key).unwrap();
+        assert_eq!(grandchild_node.parent_key, Some(new_child_key));
+    }
+}
+
+#[test]
+fn test_remap_key_with_root() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys.clone();
+
+    let new_root_key = tree.remap_key(root_key).unwrap();
+
+    assert_eq!(tree.root_key(), Some(new_root_key));
+    assert!(!tree.contains(root_key));
+
+    let root_node = tree.get(new_root_key).unwrap();
+    assert_eq!(*root_node.value, 0);
+    assert!(root_node.parent_key.is_none());
+    assert_eq!(*root_node.child_keys, child_keys);
+
+    for child_key in child_keys {
+        assert_eq!(tree.get(child_key).unwrap().parent_key, Some(new_root_key));
+    }
+}
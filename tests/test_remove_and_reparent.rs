@@ -0,0 +1,124 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_remove_and_reparent_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(declarative_tree.remove_and_reparent(0).is_none());
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_non_existent_key() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(declarative_tree.remove_and_reparent(100).is_none());
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_leaf_key() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert_eq!(declarative_tree.remove_and_reparent(1), Some('b'));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'c', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_inner_key() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 4, 'e', [
+                node! { 6, 'g', [] },
+            ] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert_eq!(declarative_tree.remove_and_reparent(1), Some('b'));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+        node! { 4, 'e', [
+            node! { 6, 'g', [] },
+        ] },
+        node! { 5, 'f', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_single_root_tree() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [] }));
+
+    assert_eq!(declarative_tree.remove_and_reparent(0), Some('a'));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_root_key_and_single_child() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 2, 'c', [] },
+            node! { 3, 'd', [] },
+        ] },
+    ] }));
+
+    assert_eq!(declarative_tree.remove_and_reparent(0), Some('a'));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 1, 'b', [
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_and_reparent_with_root_key_and_multiple_children() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(declarative_tree.remove_and_reparent(0).is_none());
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
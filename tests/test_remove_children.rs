@@ -0,0 +1,87 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::{
+    fixtures::medium,
+    DeclarativeTree,
+};
+
+#[test]
+fn test_remove_children_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert_eq!(declarative_tree.remove_children(0), None);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_children_with_leaf() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert_eq!(declarative_tree.remove_children(1), Some(0));
+    assert_eq!(declarative_tree.remove_children(100), None);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_children_with_non_root() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [
+                node! { 5, 'f', [] },
+            ] },
+            node! { 4, 'e', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert_eq!(declarative_tree.remove_children(1), Some(3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_children_with_root() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert_eq!(declarative_tree.remove_children(0), Some(3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_remove_children_with_medium_tree() {
+    let mut tree = medium();
+    let key = tree.key_of(&1).unwrap();
+
+    assert_eq!(tree.remove_children(key, Some(12)), Some(12));
+    assert_eq!(tree.len(), 28);
+    assert_eq!(tree.is_leaf(key), Some(true));
+    assert!(!tree.contains_value(&4));
+    assert!(!tree.contains_value(&13));
+}
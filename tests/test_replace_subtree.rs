@@ -0,0 +1,80 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_replace_subtree_with_non_existent_key() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree
+        .replace_subtree(DefaultKey::default(), depth_2_tree())
+        .is_none());
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_replace_subtree_with_root_key() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let removed_tree = tree
+        .replace_subtree(root_key, linear_depth_4_tree())
+        .unwrap();
+
+    assert!(removed_tree.structurally_eq(&depth_2_tree()));
+    assert_eq!(removed_tree.root_key(), Some(root_key));
+    assert!(tree.structurally_eq(&linear_depth_4_tree()));
+}
+
+#[test]
+fn test_replace_subtree_with_empty_replacement() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+
+    let removed_tree = tree.replace_subtree(child_key, Tree::default()).unwrap();
+
+    assert_eq!(removed_tree.len(), 1);
+    assert_eq!(tree.len(), 3);
+    assert_eq!(tree.get(root_key).unwrap().child_keys.len(), 2);
+}
+
+#[test]
+fn test_replace_subtree_keeps_position() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+    let expected_removed_tree = tree.clone_subtree(child_key).unwrap();
+
+    let mut replacement = Tree::<DefaultKey, usize>::default();
+    let replacement_root_key = replacement.insert_root(100);
+    replacement.insert(101, replacement_root_key).unwrap();
+
+    let removed_tree = tree.replace_subtree(child_key, replacement).unwrap();
+
+    assert_eq!(removed_tree.len(), 13);
+    assert!(removed_tree.structurally_eq(&expected_removed_tree));
+
+    assert_eq!(tree.len(), 40 - 13 + 2);
+    assert!(!tree.contains(child_key));
+
+    let child_values = tree
+        .get(root_key)
+        .unwrap()
+        .child_keys
+        .iter()
+        .map(|&child_key| tree[child_key])
+        .collect::<Vec<_>>();
+    assert_eq!(child_values, vec![1, 100, 3]);
+
+    let new_child_key = tree.get(root_key).unwrap().child_keys[1];
+    assert_eq!(tree.get(new_child_key).unwrap().parent_key, Some(root_key));
+    assert_eq!(tree.subtree_size(new_child_key), Some(2),);
+}
@@ -0,0 +1,93 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::{
+    fixtures::linear_depth_4_tree,
+    DeclarativeTree,
+};
+
+#[test]
+fn test_reroot_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.reroot(0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_reroot_at_root() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.reroot(0));
+    assert!(!declarative_tree.reroot(3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+        node! { 2, 'c', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_reroot_with_linear_tree() {
+    let mut tree = linear_depth_4_tree();
+    let leaf_key = tree.leaves().next().unwrap().0;
+
+    assert!(tree.reroot(leaf_key));
+    assert_eq!(tree.root_key(), Some(leaf_key));
+    assert_eq!(tree.len(), 4);
+
+    let values = tree
+        .preorder(leaf_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![3, 2, 1, 0]);
+
+    let (old_root_key, _) = tree.find(|&value| value == 0).unwrap();
+
+    assert_eq!(
+        tree.leaves().map(|(key, _)| key).collect::<Vec<_>>(),
+        vec![old_root_key]
+    );
+}
+
+#[test]
+fn test_reroot_with_branching_tree() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 4, 'e', [
+                node! { 6, 'g', [] },
+            ] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.reroot(4));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 4, 'e', [
+        node! { 6, 'g', [] },
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 5, 'f', [] },
+            node! { 0, 'a', [
+                node! { 2, 'c', [] },
+            ] },
+        ] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
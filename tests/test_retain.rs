@@ -0,0 +1,87 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_retain_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<usize, char>::from_declarative_node(None);
+
+    declarative_tree.retain(|_, _| false);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_retain_with_everything_retained() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    declarative_tree.retain(|_, _| true);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_retain_with_root_failing() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [] },
+    ] }));
+
+    declarative_tree.retain(|id, _| id != 0);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_retain_removes_entire_subtrees() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'x', [
+            node! { 4, 'e', [] },
+            node! { 5, 'f', [] },
+        ] },
+        node! { 2, 'c', [
+            node! { 6, 'x', [] },
+            node! { 7, 'g', [
+                node! { 8, 'x', [] },
+                node! { 9, 'h', [] },
+            ] },
+        ] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    let mut visited_ids = vec![];
+    declarative_tree.retain(|id, &value| {
+        visited_ids.push(id);
+        value != 'x'
+    });
+    visited_ids.sort();
+
+    assert_eq!(visited_ids, vec![0, 1, 2, 3, 6, 7, 8, 9]);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'c', [
+            node! { 7, 'g', [
+                node! { 9, 'h', [] },
+            ] },
+        ] },
+        node! { 3, 'd', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
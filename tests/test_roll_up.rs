@@ -0,0 +1,51 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_roll_up_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    tree.roll_up(|value, child_value| *value += child_value);
+
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_roll_up_computes_subtree_sums() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.insert_root(1);
+    let child_key_1 = tree.insert(2, root_key).unwrap();
+    let child_key_2 = tree.insert(3, root_key).unwrap();
+    let grandchild_key_1 = tree.insert(4, child_key_1).unwrap();
+    let grandchild_key_2 = tree.insert(5, child_key_1).unwrap();
+    let great_grandchild_key = tree.insert(6, grandchild_key_2).unwrap();
+
+    tree.roll_up(|value, child_value| *value += child_value);
+
+    assert_eq!(*tree.get(root_key).unwrap().value, 21);
+    assert_eq!(*tree.get(child_key_1).unwrap().value, 17);
+    assert_eq!(*tree.get(child_key_2).unwrap().value, 3);
+    assert_eq!(*tree.get(grandchild_key_1).unwrap().value, 4);
+    assert_eq!(*tree.get(grandchild_key_2).unwrap().value, 11);
+    assert_eq!(*tree.get(great_grandchild_key).unwrap().value, 6);
+}
+
+#[test]
+fn test_roll_up_with_medium_tree() {
+    let mut tree = medium();
+    let total = tree.len() * (tree.len() - 1) / 2;
+
+    tree.roll_up(|value, child_value| *value += child_value);
+
+    let (root_key, root_node) = tree.root_key_value().unwrap();
+    assert_eq!(*root_node.value, total);
+
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let child_value = *tree.get(child_key).unwrap().value;
+    assert_eq!(child_value, 1 + (4 + 5 + 6) + (13..=21).sum::<usize>());
+}
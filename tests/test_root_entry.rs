@@ -0,0 +1,59 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::depth_2_tree;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_root_entry_or_insert_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_node = tree.root_entry().or_insert(5);
+    assert!(root_node.parent_key.is_none());
+    assert!(root_node.child_keys.is_empty());
+    *root_node.value += 1;
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[tree.root_key().unwrap()], 6);
+}
+
+#[test]
+fn test_root_entry_or_insert_with_existing_root() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let root_node = tree.root_entry().or_insert(100);
+    assert_eq!(*root_node.value, 0);
+    assert_eq!(root_node.child_keys.len(), 3);
+    *root_node.value = 10;
+
+    assert_eq!(tree.len(), 4);
+    assert_eq!(tree.root_key(), Some(root_key));
+    assert_eq!(tree[root_key], 10);
+}
+
+#[test]
+fn test_root_entry_or_insert_with_calls_closure_only_when_empty() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let mut call_count = 0;
+
+    *tree
+        .root_entry()
+        .or_insert_with(|| {
+            call_count += 1;
+            1
+        })
+        .value += 1;
+    *tree
+        .root_entry()
+        .or_insert_with(|| {
+            call_count += 1;
+            1
+        })
+        .value += 1;
+
+    assert_eq!(call_count, 1);
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[tree.root_key().unwrap()], 3);
+}
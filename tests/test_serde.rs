@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+fn preorder_shape(tree: &Tree<DefaultKey, usize>) -> Vec<(usize, Vec<usize>)> {
+    tree.root_key()
+        .and_then(|root_key| tree.preorder(root_key))
+        .into_iter()
+        .flatten()
+        .map(|(_, node)| {
+            let child_values = node
+                .child_keys
+                .iter()
+                .map(|&child_key| *tree.get(child_key).unwrap().value)
+                .collect();
+            (*node.value, child_values)
+        })
+        .collect()
+}
+
+#[test]
+fn test_serde_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_eq!(json, "[]");
+
+    let deserialized_tree: Tree<DefaultKey, usize> = serde_json::from_str(&json).unwrap();
+    assert!(deserialized_tree.is_empty());
+}
+
+#[test]
+fn test_serde_with_small_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let root_key = tree.insert_root(0);
+    let child_key = tree.insert(1, root_key).unwrap();
+    tree.insert(2, root_key).unwrap();
+    tree.insert(3, child_key).unwrap();
+
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_eq!(json, "[[null,0],[0,1],[0,2],[1,3]]");
+}
+
+#[test]
+fn test_serde_round_trip_with_medium_tree() {
+    let tree = medium();
+
+    let json = serde_json::to_string(&tree).unwrap();
+    let deserialized_tree: Tree<DefaultKey, usize> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized_tree.len(), tree.len());
+    assert_eq!(preorder_shape(&deserialized_tree), preorder_shape(&tree));
+}
+
+#[test]
+fn test_serde_with_invalid_entries() {
+    assert!(serde_json::from_str::<Tree<DefaultKey, usize>>("[[0,0]]").is_err());
+    assert!(serde_json::from_str::<Tree<DefaultKey, usize>>("[[null,0],[null,1]]").is_err());
+    assert!(serde_json::from_str::<Tree<DefaultKey, usize>>("[[null,0],[2,1],[0,2]]").is_err());
+}
@@ -0,0 +1,33 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::large;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_shrink_to_fit_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    tree.shrink_to_fit();
+
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_shrink_to_fit_preserves_tree() {
+    let mut tree = large();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[1];
+    tree.remove(child_key, None);
+
+    let expected_tree = tree.clone();
+    let capacity = tree.capacity();
+
+    tree.shrink_to_fit();
+
+    assert_eq!(tree.capacity(), capacity);
+    assert_eq!(tree.len(), 85 - 21);
+    assert!(tree.structurally_eq(&expected_tree));
+    assert!(tree.iter().all(|(key, _)| expected_tree.contains(key)));
+}
@@ -0,0 +1,41 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::depth_2_tree;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_sibling_group_values_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.sibling_group_values(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_sibling_group_values_with_root() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let sibling_group = tree.sibling_group_values(root_key).unwrap();
+
+    assert_eq!(sibling_group, vec![(root_key, &0)]);
+}
+
+#[test]
+fn test_sibling_group_values_with_child() {
+    let tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys;
+
+    let sibling_group = tree.sibling_group_values(child_keys[1]).unwrap();
+
+    assert_eq!(
+        sibling_group,
+        vec![
+            (child_keys[0], &1),
+            (child_keys[1], &2),
+            (child_keys[2], &3),
+        ],
+    );
+}
@@ -0,0 +1,52 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_siblings_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.siblings(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_siblings_with_root_key() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.siblings(root_key).unwrap().count(), 0);
+}
+
+#[test]
+fn test_siblings_with_only_child() {
+    let tree = linear_depth_4_tree();
+    let (leaf_key, _) = tree.leaves().next().unwrap();
+
+    assert_eq!(tree.siblings(leaf_key).unwrap().count(), 0);
+}
+
+#[test]
+fn test_siblings_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys.clone();
+
+    assert_eq!(
+        tree.siblings(child_keys[0]).unwrap().collect::<Vec<_>>(),
+        vec![child_keys[1], child_keys[2]],
+    );
+    assert_eq!(
+        tree.siblings(child_keys[1]).unwrap().collect::<Vec<_>>(),
+        vec![child_keys[0], child_keys[2]],
+    );
+    assert_eq!(
+        tree.siblings(child_keys[2]).unwrap().collect::<Vec<_>>(),
+        vec![child_keys[0], child_keys[1]],
+    );
+}
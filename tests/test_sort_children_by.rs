@@ -0,0 +1,102 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_sort_children_by_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.sort_children_by(0, |value_1, value_2| value_1.cmp(value_2)));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_with_non_existent_key() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'c', [] },
+        node! { 2, 'b', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.sort_children_by(100, |value_1, value_2| value_1.cmp(value_2)));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_with_leaf_key() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'c', [] },
+        node! { 2, 'b', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(declarative_tree.sort_children_by(1, |value_1, value_2| value_1.cmp(value_2)));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_keeps_subtrees_attached() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'd', [
+            node! { 4, 'z', [] },
+            node! { 5, 'y', [] },
+        ] },
+        node! { 2, 'b', [] },
+        node! { 3, 'c', [
+            node! { 6, 'x', [] },
+        ] },
+    ] }));
+
+    assert!(declarative_tree.sort_children_by(0, |value_1, value_2| value_1.cmp(value_2)));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 2, 'b', [] },
+        node! { 3, 'c', [
+            node! { 6, 'x', [] },
+        ] },
+        node! { 1, 'd', [
+            node! { 4, 'z', [] },
+            node! { 5, 'y', [] },
+        ] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_is_stable() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'c', [] },
+        node! { 2, 'b', [] },
+        node! { 3, 'c', [] },
+        node! { 4, 'a', [] },
+        node! { 5, 'b', [] },
+    ] }));
+
+    assert!(declarative_tree.sort_children_by(0, |value_1, value_2| value_2.cmp(value_1)));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'c', [] },
+        node! { 3, 'c', [] },
+        node! { 2, 'b', [] },
+        node! { 5, 'b', [] },
+        node! { 4, 'a', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
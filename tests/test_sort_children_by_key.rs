@@ -0,0 +1,59 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_sort_children_by_key_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, (char, usize)>::from_declarative_node(None);
+
+    assert!(!declarative_tree.sort_children_by_key(0, |&(_, rank)| rank));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_key_with_non_existent_key() {
+    let declarative_node = node! { 0, ('a', 0), [
+        node! { 1, ('b', 2), [] },
+        node! { 2, ('c', 1), [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.sort_children_by_key(100, |&(_, rank)| rank));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_sort_children_by_key_is_stable() {
+    let mut declarative_tree =
+        DeclarativeTree::from_declarative_node(Some(&node! { 0, ('a', 0), [
+            node! { 1, ('b', 2), [
+                node! { 5, ('f', 0), [] },
+            ] },
+            node! { 2, ('c', 1), [] },
+            node! { 3, ('d', 2), [] },
+            node! { 4, ('e', 1), [] },
+        ] }));
+
+    assert!(declarative_tree.sort_children_by_key(0, |&(_, rank)| rank));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, ('a', 0), [
+        node! { 2, ('c', 1), [] },
+        node! { 4, ('e', 1), [] },
+        node! { 1, ('b', 2), [
+            node! { 5, ('f', 0), [] },
+        ] },
+        node! { 3, ('d', 2), [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
@@ -0,0 +1,82 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_structurally_eq_with_empty_trees() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.structurally_eq(&Tree::default()));
+    assert!(!tree.structurally_eq(&depth_2_tree()));
+    assert!(!depth_2_tree().structurally_eq(&tree));
+}
+
+#[test]
+fn test_structurally_eq_with_independently_built_trees() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let root_key = tree.insert_root(0);
+    tree.insert(1, root_key).unwrap();
+    tree.insert(2, root_key).unwrap();
+    tree.insert(3, root_key).unwrap();
+
+    // Insert and remove some values first so that the keys of both trees
+    // differ.
+    let mut other_tree = Tree::<DefaultKey, usize>::default();
+    let other_root_key = other_tree.insert_root(100);
+    other_tree.insert(101, other_root_key).unwrap();
+    other_tree.clear();
+    let other_root_key = other_tree.insert_root(0);
+    other_tree.insert(1, other_root_key).unwrap();
+    other_tree.insert(2, other_root_key).unwrap();
+    other_tree.insert(3, other_root_key).unwrap();
+
+    assert!(tree.structurally_eq(&other_tree));
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_structurally_eq_with_different_values() {
+    let tree = depth_2_tree();
+    let mut other_tree = depth_2_tree();
+    let other_root_key = other_tree.root_key().unwrap();
+    let other_child_key = other_tree.get(other_root_key).unwrap().child_keys[2];
+    other_tree.set(other_child_key, 4);
+
+    assert!(!tree.structurally_eq(&other_tree));
+}
+
+#[test]
+fn test_structurally_eq_with_different_child_order() {
+    let tree = depth_2_tree();
+    let mut other_tree = depth_2_tree();
+    let other_root_key = other_tree.root_key().unwrap();
+    other_tree.reorder_children(other_root_key, |child_keys| {
+        child_keys.iter().rev().copied().collect()
+    });
+
+    assert!(!tree.structurally_eq(&other_tree));
+}
+
+#[test]
+fn test_structurally_eq_with_different_shapes() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let root_key = tree.insert_root(0);
+    let child_key = tree.insert(1, root_key).unwrap();
+    tree.insert(2, child_key).unwrap();
+    tree.insert(3, root_key).unwrap();
+
+    assert_eq!(tree.len(), linear_depth_4_tree().len());
+    assert!(!tree.structurally_eq(&linear_depth_4_tree()));
+}
+
+#[test]
+fn test_structurally_eq_with_medium_trees() {
+    assert!(medium().structurally_eq(&medium()));
+}
@@ -0,0 +1,59 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_subtree_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.subtree(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_subtree_with_single_root_tree() {
+    let tree = single_root_tree();
+    let root_key = tree.root_key().unwrap();
+
+    let keys = tree
+        .subtree(root_key)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+
+    assert_eq!(keys, vec![root_key]);
+}
+
+#[test]
+fn test_subtree_with_medium_tree() {
+    let tree = medium();
+    let key = tree.key_of(&2).unwrap();
+
+    let values = tree
+        .subtree(key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![2, 7, 22, 23, 24, 8, 25, 26, 27, 9, 28, 29, 30],);
+}
+
+#[test]
+fn test_subtree_with_early_exit() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    let values = tree
+        .subtree(root_key)
+        .unwrap()
+        .map(|(_, node)| *node.value)
+        .take_while(|&value| value != 5)
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![0, 1, 4, 13, 14, 15]);
+}
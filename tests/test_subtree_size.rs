@@ -0,0 +1,52 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    linear_depth_4_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_subtree_size_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(tree.subtree_size(DefaultKey::default()).is_none());
+}
+
+#[test]
+fn test_subtree_size_with_linear_tree() {
+    let tree = linear_depth_4_tree();
+    let root_key = tree.root_key().unwrap();
+    let (leaf_key, _) = tree.leaves().next().unwrap();
+
+    assert_eq!(tree.subtree_size(root_key), Some(4));
+    assert_eq!(tree.subtree_size(leaf_key), Some(1));
+}
+
+#[test]
+fn test_subtree_size_with_medium_tree() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+
+    assert_eq!(tree.subtree_size(root_key), Some(40));
+
+    for &child_key in tree.get(root_key).unwrap().child_keys {
+        assert_eq!(tree.subtree_size(child_key), Some(13));
+    }
+}
+
+#[test]
+fn test_subtree_size_as_remove_size_hint() {
+    let mut tree = large();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    let subtree_size = tree.subtree_size(child_key).unwrap();
+    assert_eq!(subtree_size, 21);
+
+    tree.remove(child_key, Some(subtree_size - 1)).unwrap();
+    assert_eq!(tree.len(), 85 - 21);
+}
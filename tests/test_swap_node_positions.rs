@@ -0,0 +1,127 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_swap_node_positions_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert!(!declarative_tree.swap_node_positions(0, 1));
+    assert!(!declarative_tree.swap_node_positions(0, 0));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_swap_node_positions_with_same_or_ancestral_keys() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 2, 'c', [] },
+        ] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert!(!declarative_tree.swap_node_positions(1, 1));
+    assert!(!declarative_tree.swap_node_positions(0, 2));
+    assert!(!declarative_tree.swap_node_positions(2, 1));
+    assert!(!declarative_tree.swap_node_positions(2, 100));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_swap_node_positions_with_same_parent() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 4, 'e', [] },
+        ] },
+        node! { 2, 'c', [] },
+        node! { 3, 'd', [] },
+    ] }));
+
+    assert!(declarative_tree.swap_node_positions(1, 3));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 3, 'd', [] },
+        node! { 2, 'c', [] },
+        node! { 1, 'b', [
+            node! { 4, 'e', [] },
+        ] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_swap_node_positions_across_branches() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 4, 'e', [
+                node! { 5, 'f', [] },
+            ] },
+            node! { 6, 'g', [] },
+        ] },
+        node! { 2, 'c', [
+            node! { 7, 'h', [
+                node! { 8, 'i', [] },
+            ] },
+            node! { 9, 'j', [] },
+        ] },
+    ] }));
+
+    assert!(declarative_tree.swap_node_positions(4, 9));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+            node! { 9, 'j', [] },
+            node! { 6, 'g', [] },
+        ] },
+        node! { 2, 'c', [
+            node! { 7, 'h', [
+                node! { 8, 'i', [] },
+            ] },
+            node! { 4, 'e', [
+                node! { 5, 'f', [] },
+            ] },
+        ] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_swap_node_positions_across_depths() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [
+                node! { 4, 'e', [] },
+            ] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert!(declarative_tree.swap_node_positions(4, 2));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [
+                node! { 2, 'c', [] },
+            ] },
+        ] },
+        node! { 4, 'e', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
@@ -0,0 +1,80 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_swap_subtrees_with_non_existent_keys() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+
+    assert!(!tree.swap_subtrees(child_key, DefaultKey::default()));
+    assert!(!tree.swap_subtrees(DefaultKey::default(), child_key));
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_swap_subtrees_with_ancestral_keys() {
+    let mut tree = medium();
+    let key_of = |tree: &Tree<DefaultKey, usize>, value| tree.key_of(&value).unwrap();
+
+    let (root_key, leaf_key) = (key_of(&tree, 0), key_of(&tree, 13));
+    assert!(!tree.swap_subtrees(root_key, leaf_key));
+
+    let (parent_key, child_key) = (key_of(&tree, 1), key_of(&tree, 4));
+    assert!(!tree.swap_subtrees(child_key, parent_key));
+
+    assert!(tree.structurally_eq(&medium()));
+}
+
+#[test]
+fn test_swap_subtrees_with_depth_2_tree() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+    let child_keys = tree.get(root_key).unwrap().child_keys.clone();
+
+    assert!(tree.swap_subtrees(child_keys[0], child_keys[2]));
+
+    let values = tree
+        .get(root_key)
+        .unwrap()
+        .child_keys
+        .iter()
+        .map(|&key| tree[key])
+        .collect::<Vec<_>>();
+
+    assert_eq!(values, vec![3, 2, 1]);
+}
+
+#[test]
+fn test_swap_subtrees_with_medium_tree() {
+    let mut tree = medium();
+    let key_of = |tree: &Tree<DefaultKey, usize>, value| tree.key_of(&value).unwrap();
+
+    let (key_1, key_2) = (key_of(&tree, 4), key_of(&tree, 12));
+
+    assert!(tree.swap_subtrees(key_1, key_2));
+    assert_eq!(tree.len(), 40);
+
+    let child_values = |tree: &Tree<DefaultKey, usize>, value| {
+        tree.get(key_of(tree, value))
+            .unwrap()
+            .child_keys
+            .iter()
+            .map(|&key| tree[key])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(child_values(&tree, 1), vec![12, 5, 6]);
+    assert_eq!(child_values(&tree, 3), vec![10, 11, 4]);
+    assert_eq!(child_values(&tree, 4), vec![13, 14, 15]);
+    assert_eq!(child_values(&tree, 12), vec![37, 38, 39]);
+    assert_eq!(tree.parent_key(key_1), Some(Some(key_of(&tree, 3))));
+    assert_eq!(tree.parent_key(key_2), Some(Some(key_of(&tree, 1))));
+}
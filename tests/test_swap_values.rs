@@ -0,0 +1,54 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    medium,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_swap_values_with_non_existent_keys() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert!(!tree.swap_values(DefaultKey::default(), DefaultKey::default()));
+
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(!tree.swap_values(root_key, DefaultKey::default()));
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_swap_values_with_same_key() {
+    let mut tree = depth_2_tree();
+    let root_key = tree.root_key().unwrap();
+
+    assert!(tree.swap_values(root_key, root_key));
+    assert!(tree.structurally_eq(&depth_2_tree()));
+}
+
+#[test]
+fn test_swap_values_with_medium_tree() {
+    let mut tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[0];
+    let grandchild_key = tree.get(child_key).unwrap().child_keys[2];
+
+    assert!(tree.swap_values(root_key, grandchild_key));
+
+    assert_eq!(tree[root_key], 6);
+    assert_eq!(tree[grandchild_key], 0);
+    assert!(tree.get(root_key).unwrap().parent_key.is_none());
+    assert_eq!(tree.get(root_key).unwrap().child_keys.len(), 3);
+    assert_eq!(
+        tree.get(grandchild_key).unwrap().parent_key,
+        Some(child_key)
+    );
+    assert_eq!(tree.get(grandchild_key).unwrap().child_keys.len(), 3);
+
+    assert!(tree.swap_values(grandchild_key, root_key));
+    assert!(tree.structurally_eq(&medium()));
+}
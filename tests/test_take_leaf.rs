@@ -0,0 +1,69 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::DeclarativeTree;
+
+#[test]
+fn test_take_leaf_with_empty_tree() {
+    let mut declarative_tree = DeclarativeTree::<_, char>::from_declarative_node(None);
+
+    assert_eq!(declarative_tree.take_leaf(0), None);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_take_leaf_with_single_element_tree() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [] }));
+
+    assert_eq!(declarative_tree.take_leaf(0), Some('a'));
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = None;
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_take_leaf_with_non_leaves() {
+    let declarative_node = node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] };
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&declarative_node));
+
+    assert_eq!(declarative_tree.take_leaf(0), None);
+    assert_eq!(declarative_tree.take_leaf(1), None);
+    assert_eq!(declarative_tree.take_leaf(100), None);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(declarative_node);
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
+
+#[test]
+fn test_take_leaf_with_leaves() {
+    let mut declarative_tree = DeclarativeTree::from_declarative_node(Some(&node! { 0, 'a', [
+        node! { 1, 'b', [
+            node! { 3, 'd', [] },
+        ] },
+        node! { 2, 'c', [] },
+    ] }));
+
+    assert_eq!(declarative_tree.take_leaf(3), Some('d'));
+    assert_eq!(declarative_tree.take_leaf(2), Some('c'));
+    assert_eq!(declarative_tree.take_leaf(0), None);
+
+    let actual_declarative_node = declarative_tree.into_declarative_node();
+    let expected_declarative_node = Some(node! { 0, 'a', [
+        node! { 1, 'b', [] },
+    ] });
+
+    assert_eq!(actual_declarative_node, expected_declarative_node);
+}
@@ -0,0 +1,62 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_to_dot_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.to_dot(), "digraph {\n}\n");
+}
+
+#[test]
+fn test_to_dot_with_single_root_tree() {
+    assert_eq!(
+        single_root_tree().to_dot(),
+        "digraph {\n    n0 [label=\"0\"];\n}\n",
+    );
+}
+
+#[test]
+fn test_to_dot_with_depth_2_tree() {
+    let expected = "digraph {
+    n0 [label=\"0\"];
+    n1 [label=\"1\"];
+    n2 [label=\"2\"];
+    n3 [label=\"3\"];
+    n0 -> n1;
+    n0 -> n2;
+    n0 -> n3;
+}
+";
+
+    assert_eq!(depth_2_tree().to_dot(), expected);
+}
+
+#[test]
+fn test_to_dot_with_nested_tree_and_escaped_labels() {
+    let mut tree = Tree::<DefaultKey, &str>::default();
+    let root_key = tree.insert_root("root");
+    let child_key = tree.insert("say \"hi\"", root_key).unwrap();
+    tree.insert("C:\\", child_key).unwrap();
+    tree.insert("b", root_key).unwrap();
+
+    let expected = "digraph {
+    n0 [label=\"root\"];
+    n1 [label=\"say \\\"hi\\\"\"];
+    n2 [label=\"C:\\\\\"];
+    n3 [label=\"b\"];
+    n0 -> n1;
+    n1 -> n2;
+    n0 -> n3;
+}
+";
+
+    assert_eq!(tree.to_dot(), expected);
+}
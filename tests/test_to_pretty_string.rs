@@ -0,0 +1,67 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    depth_2_tree,
+    linear_depth_4_tree,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_to_pretty_string_with_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.to_pretty_string(), "");
+}
+
+#[test]
+fn test_to_pretty_string_with_single_root_tree() {
+    assert_eq!(single_root_tree().to_pretty_string(), "0");
+}
+
+#[test]
+fn test_to_pretty_string_with_depth_2_tree() {
+    assert_eq!(
+        depth_2_tree().to_pretty_string(),
+        "0
+|-- 1
+|-- 2
+`-- 3",
+    );
+}
+
+#[test]
+fn test_to_pretty_string_with_linear_tree() {
+    assert_eq!(
+        linear_depth_4_tree().to_pretty_string(),
+        "0
+`-- 1
+    `-- 2
+        `-- 3",
+    );
+}
+
+#[test]
+fn test_to_pretty_string_with_nested_tree() {
+    let mut tree = Tree::<DefaultKey, &str>::default();
+    let root_key = tree.insert_root("root");
+    let child_key_1 = tree.insert("a", root_key).unwrap();
+    tree.insert("a1", child_key_1).unwrap();
+    let grandchild_key = tree.insert("a2", child_key_1).unwrap();
+    tree.insert("a2i", grandchild_key).unwrap();
+    let child_key_2 = tree.insert("b", root_key).unwrap();
+    tree.insert("b1", child_key_2).unwrap();
+
+    assert_eq!(
+        tree.to_pretty_string(),
+        "root
+|-- a
+|   |-- a1
+|   `-- a2
+|       `-- a2i
+`-- b
+    `-- b1",
+    );
+}
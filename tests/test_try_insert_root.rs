@@ -0,0 +1,48 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_try_insert_root_into_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    let root_key = tree.try_insert_root(0).unwrap();
+
+    assert_eq!(tree.root_key(), Some(root_key));
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[root_key], 0);
+}
+
+#[test]
+fn test_try_insert_root_into_single_element_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+    let root_key = tree.insert_root(0);
+
+    assert_eq!(tree.try_insert_root(1), Err(1));
+    assert_eq!(tree.root_key(), Some(root_key));
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[root_key], 0);
+}
+
+#[test]
+fn test_try_insert_root_into_multi_element_tree() {
+    let mut tree = medium();
+    let expected_tree = medium();
+
+    assert_eq!(tree.try_insert_root(100), Err(100));
+    assert!(tree.structurally_eq(&expected_tree));
+}
+
+#[test]
+fn test_try_insert_root_after_clear() {
+    let mut tree = medium();
+
+    tree.clear();
+    let root_key = tree.try_insert_root(100).unwrap();
+
+    assert_eq!(tree.root_key(), Some(root_key));
+    assert_eq!(tree.len(), 1);
+}
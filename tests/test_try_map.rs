@@ -0,0 +1,44 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_try_map_with_empty_tree() {
+    let tree = Tree::<DefaultKey, String>::default();
+
+    let mapped_tree = tree.try_map(|value| value.parse::<u32>()).unwrap();
+
+    assert!(mapped_tree.is_empty());
+}
+
+#[test]
+fn test_try_map_with_valid_values() {
+    let tree = medium().map(|value| value.to_string());
+
+    let mapped_tree = tree.try_map(|value| value.parse::<usize>()).unwrap();
+
+    assert!(mapped_tree.structurally_eq(&medium()));
+}
+
+#[test]
+fn test_try_map_with_invalid_values() {
+    let mut tree = medium().map(|value| value.to_string());
+    let (first_key, _) = tree.find(|value| value == "17").unwrap();
+    let (second_key, _) = tree.find(|value| value == "33").unwrap();
+    tree.set(first_key, "seventeen".into());
+    tree.set(second_key, "thirty-three".into());
+
+    let mut visited_count = 0;
+    let error = tree
+        .try_map(|value| {
+            visited_count += 1;
+            value.parse::<usize>().map_err(|_| value.clone())
+        })
+        .unwrap_err();
+
+    assert_eq!(error, "seventeen");
+    assert!(visited_count < tree.len());
+}
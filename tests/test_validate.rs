@@ -0,0 +1,49 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::{
+    large,
+    medium,
+    single_root_tree,
+};
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_validate_with_fixtures() {
+    assert_eq!(Tree::<DefaultKey, usize>::default().validate(), Ok(()));
+    assert_eq!(single_root_tree().validate(), Ok(()));
+    assert_eq!(medium().validate(), Ok(()));
+    assert_eq!(large().validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_after_many_mutations() {
+    let mut tree = large();
+    let key_of = |tree: &Tree<DefaultKey, usize>, value| tree.key_of(&value).unwrap();
+
+    assert!(tree.swap_subtrees(key_of(&tree, 2), key_of(&tree, 30)));
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.rebase(key_of(&tree, 5), key_of(&tree, 84)));
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.rebase(key_of(&tree, 0), key_of(&tree, 21)));
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.reroot(key_of(&tree, 40)));
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.remove(key_of(&tree, 3), None).is_some());
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.remove_and_reparent(key_of(&tree, 1)).is_some());
+    assert_eq!(tree.validate(), Ok(()));
+
+    assert!(tree.remove_children(key_of(&tree, 84), None).is_some());
+    assert_eq!(tree.validate(), Ok(()));
+
+    let key = tree.insert_before(100, key_of(&tree, 84)).unwrap();
+    assert!(tree.graft(medium(), key));
+    assert_eq!(tree.validate(), Ok(()));
+}
@@ -0,0 +1,35 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_values_with_empty_tree() {
+    let mut tree = Tree::<DefaultKey, usize>::default();
+
+    assert_eq!(tree.values().count(), 0);
+    assert_eq!(tree.values_mut().count(), 0);
+}
+
+#[test]
+fn test_values_with_medium_tree() {
+    let tree = medium();
+
+    let mut values = tree.values().copied().collect::<Vec<_>>();
+    values.sort();
+
+    assert_eq!(values, (0..40).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_values_mut_with_medium_tree() {
+    let mut tree = medium();
+
+    for value in tree.values_mut() {
+        *value += 1;
+    }
+
+    assert!(tree.structurally_eq(&medium().map(|value| value + 1)));
+}
@@ -0,0 +1,53 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use common::fixtures::medium;
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_visit_preorder_with_non_existent_key() {
+    let tree = Tree::<DefaultKey, usize>::default();
+    let mut visited_count = 0;
+
+    tree.visit_preorder(DefaultKey::default(), |_, _, _| visited_count += 1);
+
+    assert_eq!(visited_count, 0);
+}
+
+#[test]
+fn test_visit_preorder_matches_preorder() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let mut visited_keys = vec![];
+
+    tree.visit_preorder(root_key, |key, _, _| visited_keys.push(key));
+
+    assert_eq!(
+        visited_keys,
+        tree.preorder(root_key)
+            .unwrap()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn test_visit_preorder_with_relative_depths() {
+    let tree = medium();
+    let root_key = tree.root_key().unwrap();
+    let child_key = tree.get(root_key).unwrap().child_keys[2];
+    let mut lines = vec![];
+
+    tree.visit_preorder(child_key, |_, value, depth| {
+        lines.push(format!("{:indent$}{value}", "", indent = depth * 2));
+    });
+
+    assert_eq!(
+        lines,
+        vec![
+            "3", "  10", "    31", "    32", "    33", "  11", "    34", "    35", "    36",
+            "  12", "    37", "    38", "    39",
+        ],
+    );
+}
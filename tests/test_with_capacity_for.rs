@@ -0,0 +1,26 @@
+use slotmap::DefaultKey;
+use tinytree::Tree;
+
+#[test]
+fn test_with_capacity_for_empty_tree() {
+    let tree = Tree::<DefaultKey, usize>::with_capacity_for(0, 3);
+
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn test_with_capacity_for_complete_trees() {
+    for (levels, branching, expected_capacity) in [
+        (1, 0, 1),
+        (1, 5, 1),
+        (4, 1, 4),
+        (2, 3, 4),
+        (4, 3, 40),
+        (4, 4, 85),
+    ] {
+        let tree = Tree::<DefaultKey, usize>::with_capacity_for(levels, branching);
+
+        assert!(tree.is_empty());
+        assert!(tree.capacity() >= expected_capacity);
+    }
+}